@@ -0,0 +1,637 @@
+//! # twilight-embed-builder-derive
+//!
+//! Derive macro for the `IntoEmbed` trait of [`twilight-embed-builder`].
+//!
+//! This is re-exported by `twilight-embed-builder` with its `derive` feature,
+//! and shouldn't be depended on directly.
+//!
+//! [`twilight-embed-builder`]: https://docs.rs/twilight-embed-builder
+
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    rustdoc::broken_intra_doc_links,
+    unsafe_code,
+    unused
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use std::mem;
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Error, Field, Fields,
+    GenericArgument, Ident, Lit, LitInt, LitStr, Meta, NestedMeta, Path, PathArguments, Result,
+    Type,
+};
+
+/// Implement `IntoEmbed` for a struct, creating an embed from its fields.
+///
+/// Fields are annotated with `#[embed(..)]` to choose which part of the embed
+/// they become, and fields without the attribute are left out. Fields of type
+/// `Option<T>` only set their part when they're `Some`.
+///
+/// Text parts are created with `ToString`, or with `format = ".."`, which is
+/// passed to `format!` along with the field:
+///
+/// - `author`: the name of the author;
+/// - `description`;
+/// - `field`: a field, named after the struct field unless `name = ".."` is
+///   given, which is inline with `inline`, and placed by `order = n` or in the
+///   order of the struct's fields. Orders can't be negative, and fields
+///   without one have an order of 0;
+/// - `footer`: the text of the footer;
+/// - `title`;
+/// - `url`.
+///
+/// Other parts take their field as it is:
+///
+/// - `color`: a `Color`;
+/// - `image` and `thumbnail`: an `ImageSource`;
+/// - `timestamp`: a `Timestamp`.
+///
+/// Every part other than `field` can only be set by one field.
+///
+/// On the struct, `#[embed(color = 0x..)]` sets a color for every embed,
+/// unless a `color` field overrides it, and
+/// `#[embed(crate = "..")]` sets the path of `twilight-embed-builder` if it's
+/// been renamed or re-exported.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{ImageSource, IntoEmbed};
+///
+/// #[derive(IntoEmbed)]
+/// #[embed(color = 0xfd_69_b3)]
+/// struct Ticket {
+///     #[embed(title(format = "Ticket #{}"))]
+///     id: u64,
+///     #[embed(description)]
+///     summary: String,
+///     #[embed(field(inline, order = 1))]
+///     priority: &'static str,
+///     #[embed(field(inline, name = "Assigned to"))]
+///     assignee: Option<String>,
+///     #[embed(thumbnail)]
+///     avatar: Option<ImageSource>,
+///     #[embed(footer(format = "Opened by {}"))]
+///     author: String,
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let ticket = Ticket {
+///     id: 42,
+///     summary: "The bot doesn't respond to commands.".to_owned(),
+///     priority: "High",
+///     assignee: Some("Twilight".to_owned()),
+///     avatar: None,
+///     author: "Applejack".to_owned(),
+/// };
+///
+/// let embed = ticket.to_embed().build()?;
+///
+/// assert_eq!(Some("Ticket #42"), embed.title.as_deref());
+/// assert_eq!("Assigned to", embed.fields[0].name);
+/// assert_eq!("Priority", embed.fields[1].name);
+/// assert_eq!("Opened by Applejack", embed.footer.unwrap().text);
+/// # Ok(()) }
+/// ```
+#[proc_macro_derive(IntoEmbed, attributes(embed))]
+pub fn into_embed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Part of an embed that a field becomes.
+enum Part {
+    Author,
+    Color,
+    Description,
+    Field {
+        inline: bool,
+        name: String,
+        order: u32,
+    },
+    Footer,
+    Image,
+    Thumbnail,
+    Timestamp,
+    Title,
+    Url,
+}
+
+impl Part {
+    /// Whether the part is text, and so can be formatted.
+    const fn is_text(&self) -> bool {
+        !matches!(
+            self,
+            Self::Color | Self::Image | Self::Thumbnail | Self::Timestamp
+        )
+    }
+}
+
+/// Field annotated with the part that it becomes.
+struct Annotated<'a> {
+    field: &'a Field,
+    format: Option<LitStr>,
+    /// Name of the part in the attribute.
+    ident: Ident,
+    part: Part,
+}
+
+/// Options set on the struct.
+struct Options {
+    color: Option<LitInt>,
+    krate: Path,
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &data.fields,
+                    "IntoEmbed can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "IntoEmbed can only be derived for structs",
+            ))
+        }
+    };
+
+    let options = options(&input.attrs)?;
+    let mut annotated = Vec::new();
+
+    for field in fields {
+        for attr in embed_attrs(&field.attrs) {
+            annotated.extend(parts(field, attr)?);
+        }
+    }
+
+    // Setters overwrite each other, so every part other than fields can only
+    // be set once.
+    for (idx, later) in annotated.iter().enumerate() {
+        let duplicate = !matches!(later.part, Part::Field { .. })
+            && annotated[..idx]
+                .iter()
+                .any(|earlier| mem::discriminant(&earlier.part) == mem::discriminant(&later.part));
+
+        if duplicate {
+            return Err(Error::new_spanned(
+                &later.ident,
+                format!("`{}` can only be set by one field", later.ident),
+            ));
+        }
+    }
+
+    // Sorting is stable, so fields without an order stay in the order of the
+    // struct.
+    annotated.sort_by_key(|annotated| match annotated.part {
+        Part::Field { order, .. } => order,
+        _ => 0,
+    });
+
+    let krate = &options.krate;
+    let color = options.color.map(|color| {
+        quote_spanned! {color.span()=>
+            let builder = builder.color({
+                const COLOR: #krate::Color = #krate::macros::color(#color);
+
+                COLOR
+            });
+        }
+    });
+    let setters = annotated.iter().map(|annotated| setter(krate, annotated));
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #krate::IntoEmbed for #ident #ty_generics #where_clause {
+            fn to_embed(&self) -> #krate::EmbedBuilder {
+                let builder = #krate::EmbedBuilder::new();
+                #color
+                #(#setters)*
+
+                builder
+            }
+        }
+    })
+}
+
+/// Attributes of the macro.
+fn embed_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path.is_ident("embed"))
+}
+
+/// Parse the options set on the struct.
+fn options(attrs: &[Attribute]) -> Result<Options> {
+    let mut options = Options {
+        color: None,
+        krate: syn::parse_quote!(::twilight_embed_builder),
+    };
+
+    for attr in embed_attrs(attrs) {
+        for nested in nested(attr)? {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(meta)) if meta.path.is_ident("color") => {
+                    if let Lit::Int(color) = meta.lit {
+                        options.color = Some(color);
+
+                        continue;
+                    }
+
+                    return Err(Error::new_spanned(meta.lit, "expected an integer"));
+                }
+                NestedMeta::Meta(Meta::NameValue(meta)) if meta.path.is_ident("crate") => {
+                    if let Lit::Str(path) = meta.lit {
+                        options.krate = path.parse()?;
+
+                        continue;
+                    }
+
+                    return Err(Error::new_spanned(meta.lit, "expected a path in a string"));
+                }
+                other => {
+                    return Err(Error::new_spanned(
+                        other,
+                        "expected `color = ..` or `crate = \"..\"`",
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(options)
+}
+
+/// Parse the parts that a field becomes.
+fn parts<'a>(field: &'a Field, attr: &Attribute) -> Result<Vec<Annotated<'a>>> {
+    let mut parts = Vec::new();
+
+    for nested in nested(attr)? {
+        let (path, arguments) = match nested {
+            NestedMeta::Meta(Meta::Path(path)) => (path, Vec::new()),
+            NestedMeta::Meta(Meta::List(list)) => (list.path, list.nested.into_iter().collect()),
+            other => return Err(Error::new_spanned(other, "expected a part of an embed")),
+        };
+        let ident = path
+            .get_ident()
+            .ok_or_else(|| Error::new_spanned(&path, "expected a part of an embed"))?;
+        let mut part = match ident.to_string().as_str() {
+            "author" => Part::Author,
+            "color" => Part::Color,
+            "description" => Part::Description,
+            "field" => Part::Field {
+                inline: false,
+                name: field_name(field),
+                order: 0,
+            },
+            "footer" => Part::Footer,
+            "image" => Part::Image,
+            "thumbnail" => Part::Thumbnail,
+            "timestamp" => Part::Timestamp,
+            "title" => Part::Title,
+            "url" => Part::Url,
+            _ => return Err(Error::new_spanned(ident, "unknown part of an embed")),
+        };
+        let mut format = None;
+
+        for argument in arguments {
+            match (&mut part, argument) {
+                (part, NestedMeta::Meta(Meta::NameValue(meta)))
+                    if part.is_text() && meta.path.is_ident("format") =>
+                {
+                    format = Some(lit_str(meta.lit)?);
+                }
+                (Part::Field { inline, .. }, NestedMeta::Meta(Meta::Path(path)))
+                    if path.is_ident("inline") =>
+                {
+                    *inline = true;
+                }
+                (Part::Field { name, .. }, NestedMeta::Meta(Meta::NameValue(meta)))
+                    if meta.path.is_ident("name") =>
+                {
+                    *name = lit_str(meta.lit)?.value();
+                }
+                (Part::Field { order, .. }, NestedMeta::Meta(Meta::NameValue(meta)))
+                    if meta.path.is_ident("order") =>
+                {
+                    *order = match meta.lit {
+                        Lit::Int(lit) => lit.base10_parse()?,
+                        other => return Err(Error::new_spanned(other, "expected an integer")),
+                    };
+                }
+                (_, other) => {
+                    return Err(Error::new_spanned(
+                        other,
+                        "unknown option for this part of an embed",
+                    ))
+                }
+            }
+        }
+
+        parts.push(Annotated {
+            field,
+            format,
+            ident: ident.clone(),
+            part,
+        });
+    }
+
+    Ok(parts)
+}
+
+/// Parse the arguments of an attribute.
+fn nested(attr: &Attribute) -> Result<Vec<NestedMeta>> {
+    match attr.parse_meta()? {
+        Meta::List(list) => Ok(list.nested.into_iter().collect()),
+        other => Err(Error::new_spanned(other, "expected `#[embed(..)]`")),
+    }
+}
+
+/// Parse a string literal.
+fn lit_str(lit: Lit) -> Result<LitStr> {
+    match lit {
+        Lit::Str(lit) => Ok(lit),
+        other => Err(Error::new_spanned(other, "expected a string")),
+    }
+}
+
+/// Default name of an embed field, which is the name of the struct field with
+/// spaces instead of underscores, starting with an uppercase letter.
+fn field_name(field: &Field) -> String {
+    let ident = field
+        .ident
+        .as_ref()
+        .map(Ident::to_string)
+        .unwrap_or_default();
+    let ident = ident.strip_prefix("r#").unwrap_or(&ident).replace('_', " ");
+    let mut chars = ident.trim().chars();
+
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+/// Whether a type is an `Option`.
+fn is_option(ty: &Type) -> bool {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return false,
+    };
+
+    path.segments.last().map_or(false, |segment| {
+        segment.ident == "Option"
+            && matches!(
+                &segment.arguments,
+                PathArguments::AngleBracketed(arguments)
+                    if matches!(arguments.args.first(), Some(GenericArgument::Type(_)))
+            )
+    })
+}
+
+/// Statement setting the part of an embed from a field.
+fn setter(krate: &Path, annotated: &Annotated<'_>) -> TokenStream2 {
+    let field = annotated.field;
+    let ident = &field.ident;
+    let span = field.span();
+    let value = Ident::new("value", Span::mixed_site());
+    let text = if let Some(format) = &annotated.format {
+        quote_spanned!(span=> ::std::format!(#format, #value))
+    } else {
+        quote_spanned!(span=> ::std::string::ToString::to_string(#value))
+    };
+    let owned = quote_spanned!(span=> ::std::clone::Clone::clone(#value));
+    let set = match &annotated.part {
+        Part::Author => quote_spanned! {span=>
+            builder.author(#krate::EmbedAuthorBuilder::new(#text))
+        },
+        Part::Color => quote_spanned!(span=> builder.color(#owned)),
+        Part::Description => quote_spanned!(span=> builder.description(#text)),
+        Part::Field { inline, name, .. } => {
+            let inline = inline.then(|| quote!(.inline()));
+
+            quote_spanned! {span=>
+                builder.field(#krate::EmbedFieldBuilder::new(#name, #text)#inline)
+            }
+        }
+        Part::Footer => quote_spanned! {span=>
+            builder.footer(#krate::EmbedFooterBuilder::new(#text))
+        },
+        Part::Image => quote_spanned!(span=> builder.image(#owned)),
+        Part::Thumbnail => quote_spanned!(span=> builder.thumbnail(#owned)),
+        Part::Timestamp => quote_spanned!(span=> builder.timestamp(#owned)),
+        Part::Title => quote_spanned!(span=> builder.title(#text)),
+        Part::Url => quote_spanned!(span=> builder.url(#text)),
+    };
+
+    if is_option(&field.ty) {
+        quote! {
+            let builder = match &self.#ident {
+                ::std::option::Option::Some(#value) => #set,
+                ::std::option::Option::None => builder,
+            };
+        }
+    } else {
+        quote! {
+            let builder = {
+                let #value = &self.#ident;
+
+                #set
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand, field_name};
+    use syn::{parse_quote, DeriveInput, FieldsNamed};
+
+    fn error(input: &DeriveInput) -> String {
+        expand(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn unknown_part() {
+        let input = parse_quote! {
+            struct Value {
+                #[embed(heading)]
+                text: String,
+            }
+        };
+
+        assert_eq!("unknown part of an embed", error(&input));
+    }
+
+    #[test]
+    fn format_not_text() {
+        let input = parse_quote! {
+            struct Value {
+                #[embed(color(format = "{}"))]
+                color: Color,
+            }
+        };
+
+        assert_eq!("unknown option for this part of an embed", error(&input));
+    }
+
+    #[test]
+    fn not_named_struct() {
+        let tuple = parse_quote! {
+            struct Value(#[embed(title)] String);
+        };
+        let unit = parse_quote! {
+            struct Value;
+        };
+        let enumeration = parse_quote! {
+            enum Value {
+                Title(String),
+            }
+        };
+
+        assert_eq!(
+            "IntoEmbed can only be derived for structs with named fields",
+            error(&tuple)
+        );
+        assert_eq!(
+            "IntoEmbed can only be derived for structs with named fields",
+            error(&unit)
+        );
+        assert_eq!(
+            "IntoEmbed can only be derived for structs",
+            error(&enumeration)
+        );
+    }
+
+    #[test]
+    fn crate_path() {
+        let invalid = parse_quote! {
+            #[embed(crate = "..")]
+            struct Value {}
+        };
+        let not_string = parse_quote! {
+            #[embed(crate = 1)]
+            struct Value {}
+        };
+        let valid: DeriveInput = parse_quote! {
+            #[embed(crate = "::embeds::builder")]
+            struct Value {}
+        };
+
+        assert!(expand(&invalid).is_err());
+        assert_eq!("expected a path in a string", error(&not_string));
+        assert!(expand(&valid)
+            .unwrap()
+            .to_string()
+            .contains(":: embeds :: builder :: IntoEmbed"));
+    }
+
+    #[test]
+    fn struct_options() {
+        let color = parse_quote! {
+            #[embed(color = "red")]
+            struct Value {}
+        };
+        let unknown = parse_quote! {
+            #[embed(title = "Value")]
+            struct Value {}
+        };
+
+        assert_eq!("expected an integer", error(&color));
+        assert_eq!("expected `color = ..` or `crate = \"..\"`", error(&unknown));
+    }
+
+    #[test]
+    fn duplicate_part() {
+        let input = parse_quote! {
+            struct Value {
+                #[embed(title)]
+                name: String,
+                #[embed(title(format = "#{}"))]
+                id: u64,
+            }
+        };
+        let same_field = parse_quote! {
+            struct Value {
+                #[embed(footer, footer)]
+                name: String,
+            }
+        };
+        let fields: DeriveInput = parse_quote! {
+            struct Value {
+                #[embed(field, title)]
+                name: String,
+                #[embed(field)]
+                id: u64,
+            }
+        };
+
+        assert_eq!("`title` can only be set by one field", error(&input));
+        assert_eq!("`footer` can only be set by one field", error(&same_field));
+        assert!(expand(&fields).is_ok());
+    }
+
+    #[test]
+    fn field_order() {
+        let negative = parse_quote! {
+            struct Value {
+                #[embed(field(order = -1))]
+                name: String,
+            }
+        };
+        let too_large = parse_quote! {
+            struct Value {
+                #[embed(field(order = 4294967296))]
+                name: String,
+            }
+        };
+
+        assert!(expand(&negative).is_err());
+        assert_eq!("number too large to fit in target type", error(&too_large));
+    }
+
+    #[test]
+    fn field_names() {
+        let fields: FieldsNamed = parse_quote! {
+            {
+                r#type: String,
+                goal_difference: u32,
+                _private: bool,
+                r#match: bool,
+            }
+        };
+        let names = fields.named.iter().map(field_name).collect::<Vec<_>>();
+
+        assert_eq!(["Type", "Goal difference", "Private", "Match"], *names);
+    }
+
+    #[test]
+    fn generics() {
+        let input = parse_quote! {
+            struct Value<'a, T: Display> where T: Clone {
+                #[embed(title)]
+                title: &'a T,
+            }
+        };
+        let expanded = expand(&input).unwrap().to_string();
+
+        assert!(expanded.contains(
+            "impl < 'a , T : Display > :: twilight_embed_builder :: IntoEmbed for Value < 'a , T > where T : Clone"
+        ));
+    }
+}
@@ -0,0 +1,49 @@
+//! Accept either a built embed or a builder.
+
+use crate::{
+    typed::{EmbedState, TypedEmbedBuilder},
+    EmbedBuilder,
+};
+use twilight_model::channel::embed::Embed;
+
+/// Reference to an embed, which is either a built embed or a builder.
+///
+/// This is accepted by the renderers and [`EmbedDiff::new`]. The builders also
+/// implement [`AsRef<Embed>`], but [`AsRef`] can't be implemented for
+/// [`Embed`] itself.
+///
+/// [`EmbedDiff::new`]: crate::EmbedDiff::new
+pub trait AsEmbed {
+    /// Immutable reference to the embed.
+    fn as_embed(&self) -> &Embed;
+}
+
+impl AsEmbed for Embed {
+    fn as_embed(&self) -> &Embed {
+        self
+    }
+}
+
+impl AsEmbed for EmbedBuilder {
+    fn as_embed(&self) -> &Embed {
+        self.as_ref()
+    }
+}
+
+impl<S: EmbedState> AsEmbed for TypedEmbedBuilder<S> {
+    fn as_embed(&self) -> &Embed {
+        self.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AsEmbed;
+    use crate::{typed::HasContent, EmbedBuilder, TypedEmbedBuilder};
+    use static_assertions::assert_impl_all;
+    use twilight_model::channel::embed::Embed;
+
+    assert_impl_all!(Embed: AsEmbed);
+    assert_impl_all!(EmbedBuilder: AsEmbed, AsRef<Embed>);
+    assert_impl_all!(TypedEmbedBuilder<HasContent>: AsEmbed, AsRef<Embed>);
+}
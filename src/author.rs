@@ -1,6 +1,9 @@
 //! Create embed authors.
 
-use super::image_source::ImageSource;
+use super::{
+    image_source::ImageSource,
+    sanitize::{PartKind, Sanitizer},
+};
 use twilight_model::channel::embed::EmbedAuthor;
 
 /// Create an embed author with a builder.
@@ -40,7 +43,7 @@ impl EmbedAuthorBuilder {
     /// The author's name.
     ///
     /// Refer to [`EmbedBuilder::AUTHOR_NAME_LENGTH_LIMIT`] for the maximum
-    /// number of UTF-16 code points that can be in an author name.
+    /// length of an author name.
     ///
     /// [`EmbedBuilder::AUTHOR_NAME_LENGTH_LIMIT`]: crate::EmbedBuilder::AUTHOR_NAME_LENGTH_LIMIT
     pub fn name(self, name: impl Into<String>) -> Self {
@@ -54,6 +57,17 @@ impl EmbedAuthorBuilder {
         self
     }
 
+    /// Sanitize the author's name.
+    ///
+    /// Refer to [`Sanitizer`] for how text is sanitized.
+    ///
+    /// [`Sanitizer`]: crate::Sanitizer
+    pub fn sanitize(mut self, sanitizer: &Sanitizer) -> Self {
+        sanitizer.sanitize(PartKind::AuthorName, &mut self.0.name);
+
+        self
+    }
+
     /// The author's url.
     pub fn url(self, url: impl Into<String>) -> Self {
         self._url(url.into())
@@ -66,6 +80,44 @@ impl EmbedAuthorBuilder {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EmbedAuthorBuilder {
+    /// Deserialize an embed author builder, rejecting icon URLs that aren't
+    /// valid image sources.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut author = <EmbedAuthor as serde::Deserialize>::deserialize(deserializer)?;
+
+        if let Some(icon_url) = author.icon_url.take() {
+            author.icon_url = Some(
+                ImageSource::parse(icon_url)
+                    .map_err(serde::de::Error::custom)?
+                    .0,
+            );
+        }
+
+        Ok(Self::from(author))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for EmbedAuthorBuilder {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+impl From<EmbedAuthor> for EmbedAuthorBuilder {
+    /// Create an embed author builder from an existing embed author.
+    ///
+    /// The proxied icon URL populated by Discord is removed.
+    fn from(author: EmbedAuthor) -> Self {
+        Self(EmbedAuthor {
+            proxy_icon_url: None,
+            ..author
+        })
+    }
+}
+
 impl From<EmbedAuthorBuilder> for EmbedAuthor {
     /// Convert an embed author builder into an embed author.
     ///
@@ -85,6 +137,7 @@ mod tests {
 
     assert_impl_all!(EmbedAuthorBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
     assert_impl_all!(EmbedAuthor: From<EmbedAuthorBuilder>);
+    assert_impl_all!(EmbedAuthorBuilder: From<EmbedAuthor>);
 
     #[test]
     fn name_empty() {
@@ -125,4 +178,21 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn from_author() {
+        let author = EmbedAuthor {
+            icon_url: Some("https://example.com/1.png".to_owned()),
+            name: "an author".to_owned(),
+            proxy_icon_url: Some("https://images-ext-1.discordapp.net/1.png".to_owned()),
+            url: None,
+        };
+
+        let expected = EmbedAuthor {
+            proxy_icon_url: None,
+            ..author.clone()
+        };
+
+        assert_eq!(expected, EmbedAuthorBuilder::from(author).build());
+    }
 }
@@ -1,10 +1,20 @@
 //! Create embeds.
 
-use super::image_source::ImageSource;
+use super::{
+    author::EmbedAuthorBuilder,
+    color::Color,
+    field::EmbedFieldBuilder,
+    footer::EmbedFooterBuilder,
+    image_source::ImageSource,
+    length::LengthCounting,
+    part::EmbedPart,
+    sanitize::{PartKind, Sanitizer},
+    truncate::Truncation,
+};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
-    mem,
+    ops::ControlFlow,
 };
 use twilight_model::{
     channel::embed::{Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedThumbnail},
@@ -16,6 +26,7 @@ use twilight_model::{
 /// This is returned from [`EmbedBuilder::build`].
 #[derive(Debug)]
 pub struct EmbedError {
+    field_index: Option<usize>,
     kind: EmbedErrorType,
 }
 
@@ -26,6 +37,13 @@ impl EmbedError {
         &self.kind
     }
 
+    /// Index of the field that the error occurred in, if the error is about a
+    /// field's name or value.
+    #[must_use = "retrieving the field index has no effect if left unused"]
+    pub const fn field_index(&self) -> Option<usize> {
+        self.field_index
+    }
+
     /// Consume the error, returning the source error if there is any.
     #[allow(clippy::unused_self)]
     #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
@@ -43,6 +61,7 @@ impl EmbedError {
 impl Display for EmbedError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            EmbedErrorType::AuthorNameBlank { .. } => f.write_str("the author name is blank"),
             EmbedErrorType::AuthorNameEmpty { .. } => f.write_str("the author name is empty"),
             EmbedErrorType::AuthorNameTooLong { .. } => f.write_str("the author name is too long"),
             EmbedErrorType::ColorNotRgb { color } => {
@@ -54,14 +73,19 @@ impl Display for EmbedError {
             EmbedErrorType::ColorZero => {
                 f.write_str("the given color value is 0, which is not acceptable")
             }
+            EmbedErrorType::DescriptionBlank { .. } => f.write_str("the description is blank"),
             EmbedErrorType::DescriptionEmpty { .. } => f.write_str("the description is empty"),
             EmbedErrorType::DescriptionTooLong { .. } => f.write_str("the description is too long"),
+            EmbedErrorType::FieldNameBlank { .. } => f.write_str("the field name is blank"),
             EmbedErrorType::FieldNameEmpty { .. } => f.write_str("the field name is empty"),
             EmbedErrorType::FieldNameTooLong { .. } => f.write_str("the field name is too long"),
+            EmbedErrorType::FieldValueBlank { .. } => f.write_str("the field value is blank"),
             EmbedErrorType::FieldValueEmpty { .. } => f.write_str("the field value is empty"),
             EmbedErrorType::FieldValueTooLong { .. } => f.write_str("the field value is too long"),
+            EmbedErrorType::FooterTextBlank { .. } => f.write_str("the footer text is blank"),
             EmbedErrorType::FooterTextEmpty { .. } => f.write_str("the footer text is empty"),
             EmbedErrorType::FooterTextTooLong { .. } => f.write_str("the footer text is too long"),
+            EmbedErrorType::TitleBlank { .. } => f.write_str("the title is blank"),
             EmbedErrorType::TitleEmpty { .. } => f.write_str("the title is empty"),
             EmbedErrorType::TitleTooLong { .. } => f.write_str("the title is too long"),
             EmbedErrorType::TotalContentTooLarge { .. } => {
@@ -76,17 +100,72 @@ impl Display for EmbedError {
 
 impl Error for EmbedError {}
 
+/// Error validating an embed, containing every validation failure.
+///
+/// This is returned from [`EmbedBuilder::validate`].
+#[derive(Debug)]
+pub struct EmbedValidationError {
+    errors: Vec<EmbedError>,
+}
+
+impl EmbedValidationError {
+    /// Immutable reference to the errors that occurred.
+    ///
+    /// Errors are in the order that [`EmbedBuilder::build`] checks for them,
+    /// so the first error is the one it would have returned.
+    #[must_use = "retrieving the errors has no effect if left unused"]
+    pub fn errors(&self) -> &[EmbedError] {
+        &self.errors
+    }
+
+    /// Consume the error, returning the owned errors.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "consuming the error and retrieving the errors has no effect if left unused"]
+    pub fn into_errors(self) -> Vec<EmbedError> {
+        self.errors
+    }
+}
+
+impl Display for EmbedValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("the embed has ")?;
+        Display::fmt(&self.errors.len(), f)?;
+        f.write_str(" validation errors")?;
+
+        for (idx, error) in self.errors.iter().enumerate() {
+            f.write_str(if idx == 0 { ": " } else { "; " })?;
+
+            if let Some(field_index) = error.field_index {
+                f.write_str("field ")?;
+                Display::fmt(&field_index, f)?;
+                f.write_str(": ")?;
+            }
+
+            Display::fmt(error, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for EmbedValidationError {}
+
 /// Type of [`EmbedError`] that occurred.
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum EmbedErrorType {
+    /// Name is made up of only whitespace and invisible characters.
+    AuthorNameBlank {
+        /// Provided name.
+        name: String,
+    },
     /// Name is empty.
     AuthorNameEmpty {
         /// Provided name. Although empty, the same owned allocation is
         /// included.
         name: String,
     },
-    /// Name is longer than 256 UTF-16 code points.
+    /// Name is longer than [`EmbedBuilder::AUTHOR_NAME_LENGTH_LIMIT`].
     AuthorNameTooLong {
         /// Provided name.
         name: String,
@@ -99,17 +178,31 @@ pub enum EmbedErrorType {
     /// Color was 0. The value would be thrown out by Discord and is equivalent
     /// to null.
     ColorZero,
+    /// Description is made up of only whitespace and invisible characters.
+    DescriptionBlank {
+        /// Provided description.
+        description: String,
+    },
     /// Description is empty.
     DescriptionEmpty {
         /// Provided description. Although empty, the same owned allocation is
         /// included.
         description: String,
     },
-    /// Description is longer than 4096 UTF-16 code points.
+    /// Description is longer than [`EmbedBuilder::DESCRIPTION_LENGTH_LIMIT`].
     DescriptionTooLong {
         /// Provided description.
         description: String,
     },
+    /// Name is made up of only whitespace and invisible characters.
+    ///
+    /// Refer to [`EmbedFieldBuilder::SPACER`] for how to add blank fields.
+    FieldNameBlank {
+        /// Provided name.
+        name: String,
+        /// Provided value.
+        value: String,
+    },
     /// Name is empty.
     FieldNameEmpty {
         /// Provided name. Although empty, the same owned allocation is
@@ -118,13 +211,22 @@ pub enum EmbedErrorType {
         /// Provided value.
         value: String,
     },
-    /// Name is longer than 256 UTF-16 code points.
+    /// Name is longer than [`EmbedBuilder::FIELD_NAME_LENGTH_LIMIT`].
     FieldNameTooLong {
         /// Provided name.
         name: String,
         /// Provided value.
         value: String,
     },
+    /// Value is made up of only whitespace and invisible characters.
+    ///
+    /// Refer to [`EmbedFieldBuilder::SPACER`] for how to add blank fields.
+    FieldValueBlank {
+        /// Provided name.
+        name: String,
+        /// Provided value.
+        value: String,
+    },
     /// Value is empty.
     FieldValueEmpty {
         /// Provided name.
@@ -133,31 +235,41 @@ pub enum EmbedErrorType {
         /// included.
         value: String,
     },
-    /// Value is longer than 1024 UTF-16 code points.
+    /// Value is longer than [`EmbedBuilder::FIELD_VALUE_LENGTH_LIMIT`].
     FieldValueTooLong {
         /// Provided name.
         name: String,
         /// Provided value.
         value: String,
     },
+    /// Footer text is made up of only whitespace and invisible characters.
+    FooterTextBlank {
+        /// Provided text.
+        text: String,
+    },
     /// Footer text is empty.
     FooterTextEmpty {
         /// Provided text. Although empty, the same owned allocation is
         /// included.
         text: String,
     },
-    /// Footer text is longer than 2048 UTF-16 code points.
+    /// Footer text is longer than [`EmbedBuilder::FOOTER_TEXT_LENGTH_LIMIT`].
     FooterTextTooLong {
         /// Provided text.
         text: String,
     },
+    /// Title is made up of only whitespace and invisible characters.
+    TitleBlank {
+        /// Provided title.
+        title: String,
+    },
     /// Title is empty.
     TitleEmpty {
         /// Provided title. Although empty, the same owned allocation is
         /// included.
         title: String,
     },
-    /// Title is longer than 256 UTF-16 code points.
+    /// Title is longer than [`EmbedBuilder::TITLE_LENGTH_LIMIT`].
     TitleTooLong {
         /// Provided title.
         title: String,
@@ -182,6 +294,9 @@ pub enum EmbedErrorType {
 
 /// Create an embed with a builder.
 ///
+/// The length of text is counted against the `*_LENGTH_LIMIT` constants with
+/// the builder's [`LengthCounting`], which defaults to counting characters.
+///
 /// # Examples
 ///
 /// Refer to the [crate-level documentation] for examples.
@@ -190,37 +305,37 @@ pub enum EmbedErrorType {
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[must_use = "must be built into an embed"]
-pub struct EmbedBuilder(Embed);
+pub struct EmbedBuilder(pub(crate) Embed, pub(crate) LengthCounting);
 
 impl EmbedBuilder {
-    /// The maximum number of UTF-16 code points that can be in an author name.
+    /// The maximum length of an author name.
     pub const AUTHOR_NAME_LENGTH_LIMIT: usize = 256;
 
     /// The maximum accepted color value.
     pub const COLOR_MAXIMUM: u32 = 0xff_ff_ff;
 
-    /// The maximum number of UTF-16 code points that can be in a description.
+    /// The maximum length of a description.
     pub const DESCRIPTION_LENGTH_LIMIT: usize = 4096;
 
     /// The maximum number of fields that can be in an embed.
     pub const EMBED_FIELD_LIMIT: usize = 25;
 
-    /// The maximum total textual length of the embed in UTF-16 code points.
+    /// The maximum total textual length of the embed.
     ///
     /// This combines the text of the author name, description, footer text,
     /// field names and values, and title.
     pub const EMBED_LENGTH_LIMIT: usize = 6000;
 
-    /// The maximum number of UTF-16 code points that can be in a field name.
+    /// The maximum length of a field name.
     pub const FIELD_NAME_LENGTH_LIMIT: usize = 256;
 
-    /// The maximum number of UTF-16 code points that can be in a field value.
+    /// The maximum length of a field value.
     pub const FIELD_VALUE_LENGTH_LIMIT: usize = 1024;
 
-    /// The maximum number of UTF-16 code points that can be in a footer's text.
+    /// The maximum length of a footer's text.
     pub const FOOTER_TEXT_LENGTH_LIMIT: usize = 2048;
 
-    /// The maximum number of UTF-16 code points that can be in a title.
+    /// The maximum length of a title.
     pub const TITLE_LENGTH_LIMIT: usize = 256;
 
     /// Create a new default embed builder.
@@ -233,39 +348,54 @@ impl EmbedBuilder {
     /// [crate-level documentation]: crate
     /// [default implementation]: Self::default
     pub const fn new() -> Self {
-        EmbedBuilder(Embed {
-            author: None,
-            color: None,
-            description: None,
-            fields: Vec::new(),
-            footer: None,
-            image: None,
-            kind: String::new(),
-            provider: None,
-            thumbnail: None,
-            timestamp: None,
-            title: None,
-            url: None,
-            video: None,
-        })
+        EmbedBuilder(
+            Embed {
+                author: None,
+                color: None,
+                description: None,
+                fields: Vec::new(),
+                footer: None,
+                image: None,
+                kind: String::new(),
+                provider: None,
+                thumbnail: None,
+                timestamp: None,
+                title: None,
+                url: None,
+                video: None,
+            },
+            LengthCounting::Chars,
+        )
     }
 
     /// Build this into an embed.
     ///
+    /// Validation stops at the first error. Use [`validate`] to collect every
+    /// error at once.
+    ///
     /// # Errors
     ///
+    /// Returns an [`EmbedErrorType::AuthorNameBlank`] error type if the
+    /// provided name is made up of only whitespace and invisible characters.
+    ///
     /// Returns an [`EmbedErrorType::AuthorNameEmpty`] error type if the
     /// provided name is empty.
     ///
     /// Returns an [`EmbedErrorType::AuthorNameTooLong`] error type if the
     /// provided name is longer than [`AUTHOR_NAME_LENGTH_LIMIT`].
     ///
-    /// Returns an [`EmbedErrorType::ColorNotRgb`] error type if the provided
-    /// color is not a valid RGB integer. Refer to [`COLOR_MAXIMUM`] to know
-    /// what the maximum accepted value is.
+    /// Returns an [`EmbedErrorType::ColorNotRgb`] error type if the color is
+    /// not a valid RGB integer. Refer to [`COLOR_MAXIMUM`] to know what the
+    /// maximum accepted value is. Colors set with [`color`] are always valid,
+    /// so this can only occur for builders created from an existing embed or
+    /// deserialized.
+    ///
+    /// Returns an [`EmbedErrorType::ColorZero`] error type if the color is 0,
+    /// which is not an acceptable value. This can also only occur for builders
+    /// created from an existing embed or deserialized.
     ///
-    /// Returns an [`EmbedErrorType::ColorZero`] error type if the provided
-    /// color is 0, which is not an acceptable value.
+    /// Returns an [`EmbedErrorType::DescriptionBlank`] error type if a provided
+    /// description is made up of only whitespace and invisible characters.
     ///
     /// Returns an [`EmbedErrorType::DescriptionEmpty`] error type if a provided
     /// description is empty.
@@ -273,24 +403,38 @@ impl EmbedBuilder {
     /// Returns an [`EmbedErrorType::DescriptionTooLong`] error type if a
     /// provided description is longer than [`DESCRIPTION_LENGTH_LIMIT`].
     ///
+    /// Returns an [`EmbedErrorType::FieldNameBlank`] error type if a provided
+    /// field name is made up of only whitespace and invisible characters, and
+    /// isn't an [`EmbedFieldBuilder::SPACER`].
+    ///
     /// Returns an [`EmbedErrorType::FieldNameEmpty`] error type if a provided
     /// field name is empty.
     ///
     /// Returns an [`EmbedErrorType::FieldNameTooLong`] error type if a provided
     /// field name is longer than [`FIELD_NAME_LENGTH_LIMIT`].
     ///
+    /// Returns an [`EmbedErrorType::FieldValueBlank`] error type if a provided
+    /// field value is made up of only whitespace and invisible characters, and
+    /// isn't an [`EmbedFieldBuilder::SPACER`].
+    ///
     /// Returns an [`EmbedErrorType::FieldValueEmpty`] error type if a provided
     /// field value is empty.
     ///
     /// Returns an [`EmbedErrorType::FieldValueTooLong`] error type if a
     /// provided field value is longer than [`FIELD_VALUE_LENGTH_LIMIT`].
     ///
+    /// Returns an [`EmbedErrorType::FooterTextBlank`] error type if the
+    /// provided text is made up of only whitespace and invisible characters.
+    ///
     /// Returns an [`EmbedErrorType::FooterTextEmpty`] error type if the
     /// provided text is empty.
     ///
     /// Returns an [`EmbedErrorType::FooterTextTooLong`] error type if the
     /// provided text is longer than the limit defined at [`FOOTER_TEXT_LENGTH_LIMIT`].
     ///
+    /// Returns an [`EmbedErrorType::TitleBlank`] error type if the provided
+    /// title is made up of only whitespace and invisible characters.
+    ///
     /// Returns an [`EmbedErrorType::TitleEmpty`] error type if the provided
     /// title is empty.
     ///
@@ -314,159 +458,383 @@ impl EmbedBuilder {
     /// [`FIELD_VALUE_LENGTH_LIMIT`]: Self::FIELD_VALUE_LENGTH_LIMIT
     /// [`FOOTER_TEXT_LENGTH_LIMIT`]: Self::FOOTER_TEXT_LENGTH_LIMIT
     /// [`TITLE_LENGTH_LIMIT`]: Self::TITLE_LENGTH_LIMIT
-    #[allow(clippy::too_many_lines)]
+    /// [`color`]: Self::color
+    /// [`validate`]: Self::validate
     #[must_use = "should be used as part of something like a message"]
     pub fn build(mut self) -> Result<Embed, EmbedError> {
+        if let ControlFlow::Break(error) = self.visit_errors(ControlFlow::Break) {
+            return Err(error);
+        }
+
+        if self.0.kind.is_empty() {
+            self.0.kind = "rich".to_string();
+        }
+
+        Ok(self.0)
+    }
+
+    /// Build this into an embed, truncating text that is too long instead of
+    /// failing.
+    ///
+    /// Each part that is longer than its limit is shortened to fit, with
+    /// `ellipsis` appended. If the embed is still larger than
+    /// [`EMBED_LENGTH_LIMIT`] afterwards, then the description, field values,
+    /// footer text, field names, title and author name are shortened in that
+    /// order until it fits.
+    ///
+    /// Text is only cut between grapheme clusters, and never inside of a
+    /// markdown construct such as inline code, a code block, a masked link, a
+    /// mention, or bold text. The cut is moved before the construct instead.
+    ///
+    /// Returns the embed along with which parts were truncated and by how
+    /// much.
+    ///
+    /// # Examples
+    ///
+    /// Truncate a description that is too long:
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedPart};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let description = "twilight ".repeat(1000);
+    /// let (embed, truncations) = EmbedBuilder::new()
+    ///     .description(description)
+    ///     .build_truncated("…")?;
+    ///
+    /// assert!(embed.description.unwrap().ends_with("twilight…"));
+    /// assert_eq!(EmbedPart::Description, truncations[0].part());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the same reasons as [`build`], except for text
+    /// being too long.
+    ///
+    /// [`EMBED_LENGTH_LIMIT`]: Self::EMBED_LENGTH_LIMIT
+    /// [`build`]: Self::build
+    pub fn build_truncated(
+        mut self,
+        ellipsis: &str,
+    ) -> Result<(Embed, Vec<Truncation>), EmbedError> {
+        let truncations = crate::truncate::truncate(&mut self.0, ellipsis, self.1);
+
+        self.build().map(|embed| (embed, truncations))
+    }
+
+    /// Build this into one or more embeds, splitting content that doesn't fit
+    /// into a single embed.
+    ///
+    /// The description is split on paragraph, then line, then word boundaries
+    /// into chunks that fit within [`DESCRIPTION_LENGTH_LIMIT`], each in its
+    /// own embed. Fields are placed after the description and spill into
+    /// follow-up embeds when an embed reaches [`EMBED_FIELD_LIMIT`] or
+    /// [`EMBED_LENGTH_LIMIT`].
+    ///
+    /// The author and color are repeated on every embed. The title, URL and
+    /// thumbnail are only set on the first embed, and the footer, image and
+    /// timestamp are only set on the last.
+    ///
+    /// Markdown constructs spanning a boundary, such as a code block, may be
+    /// split between embeds.
+    ///
+    /// # Examples
+    ///
+    /// Split 30 fields across two embeds:
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedFieldBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let embeds = (1..=30)
+    ///     .fold(EmbedBuilder::new().title("results"), |builder, idx| {
+    ///         builder.field(EmbedFieldBuilder::new(idx.to_string(), "a result"))
+    ///     })
+    ///     .build_split()?;
+    ///
+    /// assert_eq!(2, embeds.len());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the same reasons as [`build`] if any of the split
+    /// embeds are invalid, such as if a field value is too long.
+    ///
+    /// [`DESCRIPTION_LENGTH_LIMIT`]: Self::DESCRIPTION_LENGTH_LIMIT
+    /// [`EMBED_FIELD_LIMIT`]: Self::EMBED_FIELD_LIMIT
+    /// [`EMBED_LENGTH_LIMIT`]: Self::EMBED_LENGTH_LIMIT
+    /// [`build`]: Self::build
+    pub fn build_split(self) -> Result<Vec<Embed>, EmbedError> {
+        let counting = self.1;
+
+        crate::split::split(self.0, counting)
+            .into_iter()
+            .map(|embed| Self(embed, counting).build())
+            .collect()
+    }
+
+    /// Validate the embed, collecting every error instead of stopping at the
+    /// first one.
+    ///
+    /// This performs the same checks as [`build`], but walks the entire embed
+    /// so that all problems can be reported at once. Use [`build`] once the
+    /// embed is valid.
+    ///
+    /// # Examples
+    ///
+    /// Report both an empty title and an empty field value:
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedErrorType, EmbedFieldBuilder};
+    ///
+    /// let error = EmbedBuilder::new()
+    ///     .title("")
+    ///     .field(EmbedFieldBuilder::new("name", ""))
+    ///     .validate()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(2, error.errors().len());
+    /// assert_eq!(Some(0), error.errors()[0].field_index());
+    /// assert!(matches!(
+    ///     error.errors()[1].kind(),
+    ///     EmbedErrorType::TitleEmpty { .. }
+    /// ));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EmbedValidationError`] containing every error that
+    /// [`build`] can return which applies to the embed.
+    ///
+    /// [`build`]: Self::build
+    pub fn validate(&self) -> Result<(), EmbedValidationError> {
+        let mut errors = Vec::new();
+
+        let _ = self.visit_errors(|error| {
+            errors.push(error);
+
+            ControlFlow::Continue(())
+        });
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(EmbedValidationError { errors })
+        }
+    }
+
+    /// Walk the embed, passing each validation error to `on_error` in the
+    /// order documented on [`build`] until it breaks.
+    ///
+    /// [`build`]: Self::build
+    #[allow(clippy::too_many_lines)]
+    fn visit_errors(
+        &self,
+        mut on_error: impl FnMut(EmbedError) -> ControlFlow<EmbedError>,
+    ) -> ControlFlow<EmbedError> {
         if self.0.fields.len() > Self::EMBED_FIELD_LIMIT {
-            return Err(EmbedError {
+            on_error(EmbedError {
+                field_index: None,
                 kind: EmbedErrorType::TooManyFields {
-                    fields: self.0.fields,
+                    fields: self.0.fields.clone(),
                 },
-            });
+            })?;
         }
 
         if let Some(color) = self.0.color {
             if color == 0 {
-                return Err(EmbedError {
+                on_error(EmbedError {
+                    field_index: None,
                     kind: EmbedErrorType::ColorZero,
-                });
+                })?;
             }
 
             if color > Self::COLOR_MAXIMUM {
-                return Err(EmbedError {
+                on_error(EmbedError {
+                    field_index: None,
                     kind: EmbedErrorType::ColorNotRgb { color },
-                });
+                })?;
             }
         }
 
-        let mut total = 0;
-
-        if let Some(author) = self.0.author.take() {
+        if let Some(author) = &self.0.author {
             if author.name.is_empty() {
-                return Err(EmbedError {
-                    kind: EmbedErrorType::AuthorNameEmpty { name: author.name },
-                });
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::AuthorNameEmpty {
+                        name: author.name.clone(),
+                    },
+                })?;
+            } else if is_blank(&author.name) {
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::AuthorNameBlank {
+                        name: author.name.clone(),
+                    },
+                })?;
             }
 
-            if author.name.chars().count() > Self::AUTHOR_NAME_LENGTH_LIMIT {
-                return Err(EmbedError {
-                    kind: EmbedErrorType::AuthorNameTooLong { name: author.name },
-                });
+            if self.1.count(&author.name) > Self::AUTHOR_NAME_LENGTH_LIMIT {
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::AuthorNameTooLong {
+                        name: author.name.clone(),
+                    },
+                })?;
             }
-
-            total += author.name.chars().count();
-
-            self.0.author.replace(author);
         }
 
-        if let Some(description) = self.0.description.take() {
+        if let Some(description) = &self.0.description {
             if description.is_empty() {
-                return Err(EmbedError {
-                    kind: EmbedErrorType::DescriptionEmpty { description },
-                });
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::DescriptionEmpty {
+                        description: description.clone(),
+                    },
+                })?;
+            } else if is_blank(description) {
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::DescriptionBlank {
+                        description: description.clone(),
+                    },
+                })?;
             }
 
-            if description.chars().count() > Self::DESCRIPTION_LENGTH_LIMIT {
-                return Err(EmbedError {
-                    kind: EmbedErrorType::DescriptionTooLong { description },
-                });
+            if self.1.count(description) > Self::DESCRIPTION_LENGTH_LIMIT {
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::DescriptionTooLong {
+                        description: description.clone(),
+                    },
+                })?;
             }
-
-            total += description.chars().count();
-            self.0.description.replace(description);
         }
 
-        if let Some(footer) = self.0.footer.take() {
+        if let Some(footer) = &self.0.footer {
             if footer.text.is_empty() {
-                return Err(EmbedError {
-                    kind: EmbedErrorType::FooterTextEmpty { text: footer.text },
-                });
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::FooterTextEmpty {
+                        text: footer.text.clone(),
+                    },
+                })?;
+            } else if is_blank(&footer.text) {
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::FooterTextBlank {
+                        text: footer.text.clone(),
+                    },
+                })?;
             }
 
-            if footer.text.chars().count() > Self::FOOTER_TEXT_LENGTH_LIMIT {
-                return Err(EmbedError {
-                    kind: EmbedErrorType::FooterTextTooLong { text: footer.text },
-                });
+            if self.1.count(&footer.text) > Self::FOOTER_TEXT_LENGTH_LIMIT {
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::FooterTextTooLong {
+                        text: footer.text.clone(),
+                    },
+                })?;
             }
-
-            total += footer.text.chars().count();
-            self.0.footer.replace(footer);
         }
 
-        {
-            let field_count = self.0.fields.len();
-            let fields = mem::replace(&mut self.0.fields, Vec::with_capacity(field_count));
-
-            for field in fields {
-                if field.name.is_empty() {
-                    return Err(EmbedError {
-                        kind: EmbedErrorType::FieldNameEmpty {
-                            name: field.name,
-                            value: field.value,
-                        },
-                    });
-                }
-
-                if field.name.chars().count() > Self::FIELD_NAME_LENGTH_LIMIT {
-                    return Err(EmbedError {
-                        kind: EmbedErrorType::FieldNameTooLong {
-                            name: field.name,
-                            value: field.value,
-                        },
-                    });
-                }
-
-                if field.value.is_empty() {
-                    return Err(EmbedError {
-                        kind: EmbedErrorType::FieldValueEmpty {
-                            name: field.name,
-                            value: field.value,
-                        },
-                    });
-                }
-
-                if field.value.chars().count() > Self::FIELD_VALUE_LENGTH_LIMIT {
-                    return Err(EmbedError {
-                        kind: EmbedErrorType::FieldValueTooLong {
-                            name: field.name,
-                            value: field.value,
-                        },
-                    });
-                }
-
-                total += field.name.chars().count() + field.value.chars().count();
-                self.0.fields.push(field);
+        for (idx, field) in self.0.fields.iter().enumerate() {
+            if field.name.is_empty() {
+                on_error(EmbedError {
+                    field_index: Some(idx),
+                    kind: EmbedErrorType::FieldNameEmpty {
+                        name: field.name.clone(),
+                        value: field.value.clone(),
+                    },
+                })?;
+            } else if field.name != EmbedFieldBuilder::SPACER && is_blank(&field.name) {
+                on_error(EmbedError {
+                    field_index: Some(idx),
+                    kind: EmbedErrorType::FieldNameBlank {
+                        name: field.name.clone(),
+                        value: field.value.clone(),
+                    },
+                })?;
+            }
+
+            if self.1.count(&field.name) > Self::FIELD_NAME_LENGTH_LIMIT {
+                on_error(EmbedError {
+                    field_index: Some(idx),
+                    kind: EmbedErrorType::FieldNameTooLong {
+                        name: field.name.clone(),
+                        value: field.value.clone(),
+                    },
+                })?;
+            }
+
+            if field.value.is_empty() {
+                on_error(EmbedError {
+                    field_index: Some(idx),
+                    kind: EmbedErrorType::FieldValueEmpty {
+                        name: field.name.clone(),
+                        value: field.value.clone(),
+                    },
+                })?;
+            } else if field.value != EmbedFieldBuilder::SPACER && is_blank(&field.value) {
+                on_error(EmbedError {
+                    field_index: Some(idx),
+                    kind: EmbedErrorType::FieldValueBlank {
+                        name: field.name.clone(),
+                        value: field.value.clone(),
+                    },
+                })?;
+            }
+
+            if self.1.count(&field.value) > Self::FIELD_VALUE_LENGTH_LIMIT {
+                on_error(EmbedError {
+                    field_index: Some(idx),
+                    kind: EmbedErrorType::FieldValueTooLong {
+                        name: field.name.clone(),
+                        value: field.value.clone(),
+                    },
+                })?;
             }
         }
 
-        if let Some(title) = self.0.title.take() {
+        if let Some(title) = &self.0.title {
             if title.is_empty() {
-                return Err(EmbedError {
-                    kind: EmbedErrorType::TitleEmpty { title },
-                });
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::TitleEmpty {
+                        title: title.clone(),
+                    },
+                })?;
+            } else if is_blank(title) {
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::TitleBlank {
+                        title: title.clone(),
+                    },
+                })?;
             }
 
-            if title.chars().count() > Self::TITLE_LENGTH_LIMIT {
-                return Err(EmbedError {
-                    kind: EmbedErrorType::TitleTooLong { title },
-                });
+            if self.1.count(title) > Self::TITLE_LENGTH_LIMIT {
+                on_error(EmbedError {
+                    field_index: None,
+                    kind: EmbedErrorType::TitleTooLong {
+                        title: title.clone(),
+                    },
+                })?;
             }
-
-            total += title.chars().count();
-            self.0.title.replace(title);
         }
 
+        let total = EmbedPart::total_length(&self.0, self.1);
+
         if total > Self::EMBED_LENGTH_LIMIT {
-            return Err(EmbedError {
+            on_error(EmbedError {
+                field_index: None,
                 kind: EmbedErrorType::TotalContentTooLarge { length: total },
-            });
+            })?;
         }
 
-        if self.0.kind.is_empty() {
-            self.0.kind = "rich".to_string();
-        }
-
-        Ok(self.0)
+        ControlFlow::Continue(())
     }
 
     /// Set the author.
@@ -496,38 +864,41 @@ impl EmbedBuilder {
         self
     }
 
+    /// Remove every field from the embed.
+    pub fn clear_fields(mut self) -> Self {
+        self.0.fields.clear();
+
+        self
+    }
+
     /// Set the color.
     ///
-    /// This must be a valid hexadecimal RGB value. `0x000000` is not an
-    /// acceptable value as it would be thrown out by Discord. Refer to
-    /// [`COLOR_MAXIMUM`] for the maximum acceptable value.
+    /// Refer to [`Color`] for the ways that a color can be created.
     ///
     /// # Examples
     ///
     /// Set the color of an embed to `0xfd69b3`:
     ///
     /// ```
-    /// use twilight_embed_builder::EmbedBuilder;
+    /// use twilight_embed_builder::{Color, EmbedBuilder};
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let embed = EmbedBuilder::new()
-    ///     .color(0xfd_69_b3)
+    ///     .color(Color::new(0xfd_69_b3)?)
     ///     .description("a description")
     ///     .build()?;
     /// # Ok(()) }
     /// ```
-    ///
-    /// [`COLOR_MAXIMUM`]: Self::COLOR_MAXIMUM
-    pub fn color(mut self, color: u32) -> Self {
-        self.0.color.replace(color);
+    pub fn color(mut self, color: Color) -> Self {
+        self.0.color.replace(color.get());
 
         self
     }
 
     /// Set the description.
     ///
-    /// Refer to [`DESCRIPTION_LENGTH_LIMIT`] for the maximum number of UTF-16
-    /// code points that can be in a description.
+    /// Refer to [`DESCRIPTION_LENGTH_LIMIT`] for the maximum length of a
+    /// description.
     ///
     /// # Examples
     ///
@@ -574,6 +945,30 @@ impl EmbedBuilder {
         self
     }
 
+    /// Add multiple fields to the embed, after any existing fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedFieldBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let embed = EmbedBuilder::new()
+    ///     .fields([
+    ///         EmbedFieldBuilder::new("wings", "she has wings"),
+    ///         EmbedFieldBuilder::new("horn", "she can do magic"),
+    ///     ])
+    ///     .build()?;
+    ///
+    /// assert_eq!(2, embed.fields.len());
+    /// # Ok(()) }
+    /// ```
+    pub fn fields(mut self, fields: impl IntoIterator<Item = impl Into<EmbedField>>) -> Self {
+        self.0.fields.extend(fields.into_iter().map(Into::into));
+
+        self
+    }
+
     /// Set the footer of the embed.
     ///
     /// # Examples
@@ -598,6 +993,78 @@ impl EmbedBuilder {
         self
     }
 
+    /// Immutable reference to the author, if set.
+    #[must_use = "retrieving the author has no effect if left unused"]
+    pub const fn get_author(&self) -> Option<&EmbedAuthor> {
+        self.0.author.as_ref()
+    }
+
+    /// Color, if set.
+    #[must_use = "retrieving the color has no effect if left unused"]
+    pub const fn get_color(&self) -> Option<u32> {
+        self.0.color
+    }
+
+    /// Immutable reference to the description, if set.
+    #[must_use = "retrieving the description has no effect if left unused"]
+    pub fn get_description(&self) -> Option<&str> {
+        self.0.description.as_deref()
+    }
+
+    /// Immutable reference to the field at an index, if there is one.
+    #[must_use = "retrieving the field has no effect if left unused"]
+    pub fn get_field(&self, index: usize) -> Option<&EmbedField> {
+        self.0.fields.get(index)
+    }
+
+    /// Immutable reference to the fields, in order.
+    #[must_use = "retrieving the fields has no effect if left unused"]
+    pub fn get_fields(&self) -> &[EmbedField] {
+        &self.0.fields
+    }
+
+    /// Immutable reference to the footer, if set.
+    #[must_use = "retrieving the footer has no effect if left unused"]
+    pub const fn get_footer(&self) -> Option<&EmbedFooter> {
+        self.0.footer.as_ref()
+    }
+
+    /// Immutable reference to the image, if set.
+    #[must_use = "retrieving the image has no effect if left unused"]
+    pub const fn get_image(&self) -> Option<&EmbedImage> {
+        self.0.image.as_ref()
+    }
+
+    /// How the length of text is counted.
+    #[must_use = "retrieving the length counting has no effect if left unused"]
+    pub const fn get_length_counting(&self) -> LengthCounting {
+        self.1
+    }
+
+    /// Immutable reference to the thumbnail, if set.
+    #[must_use = "retrieving the thumbnail has no effect if left unused"]
+    pub const fn get_thumbnail(&self) -> Option<&EmbedThumbnail> {
+        self.0.thumbnail.as_ref()
+    }
+
+    /// Timestamp, if set.
+    #[must_use = "retrieving the timestamp has no effect if left unused"]
+    pub const fn get_timestamp(&self) -> Option<Timestamp> {
+        self.0.timestamp
+    }
+
+    /// Immutable reference to the title, if set.
+    #[must_use = "retrieving the title has no effect if left unused"]
+    pub fn get_title(&self) -> Option<&str> {
+        self.0.title.as_deref()
+    }
+
+    /// Immutable reference to the URL, if set.
+    #[must_use = "retrieving the URL has no effect if left unused"]
+    pub fn get_url(&self) -> Option<&str> {
+        self.0.url.as_deref()
+    }
+
     /// Set the image.
     ///
     /// # Examples
@@ -626,6 +1093,228 @@ impl EmbedBuilder {
         self
     }
 
+    /// Insert a field at an index, shifting all fields after it to the right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedFieldBuilder};
+    ///
+    /// let builder = EmbedBuilder::new()
+    ///     .field(EmbedFieldBuilder::new("second", "value"))
+    ///     .insert_field(0, EmbedFieldBuilder::new("first", "value"));
+    ///
+    /// assert_eq!("first", builder.get_fields()[0].name);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than the number of fields.
+    pub fn insert_field(self, index: usize, field: impl Into<EmbedField>) -> Self {
+        self._insert_field(index, field.into())
+    }
+
+    fn _insert_field(mut self, index: usize, field: EmbedField) -> Self {
+        self.0.fields.insert(index, field);
+
+        self
+    }
+
+    /// Current total textual length of the embed.
+    ///
+    /// This is counted the same way as when [`build`] checks the length
+    /// against [`EMBED_LENGTH_LIMIT`].
+    ///
+    /// [`EMBED_LENGTH_LIMIT`]: Self::EMBED_LENGTH_LIMIT
+    /// [`build`]: Self::build
+    #[must_use = "retrieving the length has no effect if left unused"]
+    pub fn length(&self) -> usize {
+        EmbedPart::total_length(&self.0, self.1)
+    }
+
+    /// Set how the length of text is counted against the limits.
+    ///
+    /// This defaults to [`LengthCounting::Chars`]. Refer to
+    /// [`LengthCounting`] for what the counting applies to.
+    ///
+    /// # Examples
+    ///
+    /// Count emoji as two UTF-16 code units each:
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedPart, LengthCounting};
+    ///
+    /// let builder = EmbedBuilder::new()
+    ///     .title("🦄🦄🦄")
+    ///     .length_counting(LengthCounting::Utf16);
+    ///
+    /// assert_eq!(6, builder.length());
+    /// assert_eq!(250, builder.remaining_part_length(EmbedPart::Title));
+    /// ```
+    pub const fn length_counting(mut self, counting: LengthCounting) -> Self {
+        self.1 = counting;
+
+        self
+    }
+
+    /// Move the field at an index to another index, shifting the fields in
+    /// between.
+    ///
+    /// # Examples
+    ///
+    /// Move the last field to the front:
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedFieldBuilder};
+    ///
+    /// let builder = EmbedBuilder::new()
+    ///     .field(EmbedFieldBuilder::new("a", "value"))
+    ///     .field(EmbedFieldBuilder::new("b", "value"))
+    ///     .field(EmbedFieldBuilder::new("c", "value"))
+    ///     .move_field(2, 0);
+    ///
+    /// let names = builder
+    ///     .get_fields()
+    ///     .iter()
+    ///     .map(|field| field.name.as_str())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(["c", "a", "b"], names.as_slice());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn move_field(mut self, from: usize, to: usize) -> Self {
+        let field = self.0.fields.remove(from);
+        self.0.fields.insert(to, field);
+
+        self
+    }
+
+    /// Number of fields that can still be added before reaching
+    /// [`EMBED_FIELD_LIMIT`].
+    ///
+    /// [`EMBED_FIELD_LIMIT`]: Self::EMBED_FIELD_LIMIT
+    #[must_use = "retrieving the remaining field slots has no effect if left unused"]
+    pub fn remaining_field_slots(&self) -> usize {
+        Self::EMBED_FIELD_LIMIT.saturating_sub(self.0.fields.len())
+    }
+
+    /// Length of text that can still be added to the embed before
+    /// reaching [`EMBED_LENGTH_LIMIT`].
+    ///
+    /// [`EMBED_LENGTH_LIMIT`]: Self::EMBED_LENGTH_LIMIT
+    #[must_use = "retrieving the remaining length has no effect if left unused"]
+    pub fn remaining_length(&self) -> usize {
+        Self::EMBED_LENGTH_LIMIT.saturating_sub(self.length())
+    }
+
+    /// Length of text that can still be added to a part of the embed.
+    ///
+    /// This is the smaller of the room left within the part's own limit and
+    /// the room left within [`EMBED_LENGTH_LIMIT`]. Parts that aren't set,
+    /// including fields past the last one, count as empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedPart};
+    ///
+    /// let builder = EmbedBuilder::new().title("twilight");
+    ///
+    /// assert_eq!(248, builder.remaining_part_length(EmbedPart::Title));
+    /// assert_eq!(4096, builder.remaining_part_length(EmbedPart::Description));
+    /// assert_eq!(5992, builder.remaining_length());
+    /// ```
+    ///
+    /// [`EMBED_LENGTH_LIMIT`]: Self::EMBED_LENGTH_LIMIT
+    #[must_use = "retrieving the remaining length has no effect if left unused"]
+    pub fn remaining_part_length(&self, part: EmbedPart) -> usize {
+        let length = part.text(&self.0).map_or(0, |text| self.1.count(text));
+
+        part.limit()
+            .saturating_sub(length)
+            .min(self.remaining_length())
+    }
+
+    /// Remove the field at an index, shifting all fields after it to the
+    /// left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn remove_field(mut self, index: usize) -> Self {
+        self.0.fields.remove(index);
+
+        self
+    }
+
+    /// Replace the field at an index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn replace_field(self, index: usize, field: impl Into<EmbedField>) -> Self {
+        self._replace_field(index, field.into())
+    }
+
+    fn _replace_field(mut self, index: usize, field: EmbedField) -> Self {
+        self.0.fields[index] = field;
+
+        self
+    }
+
+    /// Retain only the fields for which the predicate returns `true`,
+    /// preserving their order.
+    ///
+    /// # Examples
+    ///
+    /// Remove fields that aren't inlined:
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedFieldBuilder};
+    ///
+    /// let builder = EmbedBuilder::new()
+    ///     .field(EmbedFieldBuilder::new("a", "value").inline())
+    ///     .field(EmbedFieldBuilder::new("b", "value"))
+    ///     .retain_fields(|field| field.inline);
+    ///
+    /// assert_eq!(1, builder.get_fields().len());
+    /// ```
+    pub fn retain_fields(mut self, f: impl FnMut(&EmbedField) -> bool) -> Self {
+        self.0.fields.retain(f);
+
+        self
+    }
+
+    /// Sanitize the text that has been set in the embed, such as the title,
+    /// description and fields.
+    ///
+    /// Text that is set after sanitizing isn't sanitized. Refer to
+    /// [`Sanitizer`] for how text is sanitized.
+    ///
+    /// [`Sanitizer`]: crate::Sanitizer
+    pub fn sanitize(mut self, sanitizer: &Sanitizer) -> Self {
+        for part in EmbedPart::present(&self.0) {
+            if let Some(text) = part.text_mut(&mut self.0) {
+                sanitizer.sanitize(PartKind::of(part), text);
+            }
+        }
+
+        self
+    }
+
+    /// Swap the fields at two indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn swap_fields(mut self, a: usize, b: usize) -> Self {
+        self.0.fields.swap(a, b);
+
+        self
+    }
+
     /// Add a thumbnail.
     ///
     /// # Examples
@@ -663,8 +1352,7 @@ impl EmbedBuilder {
 
     /// Set the title.
     ///
-    /// Refer to [`TITLE_LENGTH_LIMIT`] for the maximum number of UTF-16 code
-    /// points that can be in a title.
+    /// Refer to [`TITLE_LENGTH_LIMIT`] for the maximum length of a title.
     ///
     /// # Examples
     ///
@@ -721,6 +1409,31 @@ impl EmbedBuilder {
     }
 }
 
+/// Characters that aren't whitespace but don't render anything visible.
+const INVISIBLE: &[char] = &[
+    '\u{ad}', '\u{34f}', '\u{115f}', '\u{1160}', '\u{17b4}', '\u{17b5}', '\u{180e}', '\u{200b}',
+    '\u{200c}', '\u{200d}', '\u{200e}', '\u{200f}', '\u{202a}', '\u{202b}', '\u{202c}', '\u{202d}',
+    '\u{202e}', '\u{2060}', '\u{2061}', '\u{2062}', '\u{2063}', '\u{2064}', '\u{2066}', '\u{2067}',
+    '\u{2068}', '\u{2069}', '\u{2800}', '\u{3164}', '\u{feff}', '\u{ffa0}',
+];
+
+/// Whether non-empty text is made up of only whitespace and invisible
+/// characters, which Discord treats as empty.
+pub(crate) fn is_blank(text: &str) -> bool {
+    text.chars().all(is_invisible)
+}
+
+/// Whether a character is whitespace or doesn't render anything visible.
+pub(crate) fn is_invisible(character: char) -> bool {
+    character.is_whitespace() || INVISIBLE.contains(&character)
+}
+
+impl AsRef<Embed> for EmbedBuilder {
+    fn as_ref(&self) -> &Embed {
+        &self.0
+    }
+}
+
 impl Default for EmbedBuilder {
     /// Create an embed builder with a default embed.
     ///
@@ -730,6 +1443,155 @@ impl Default for EmbedBuilder {
     }
 }
 
+impl From<Embed> for EmbedBuilder {
+    /// Create an embed builder from an existing embed, such as one received
+    /// from Discord, in order to edit it.
+    ///
+    /// Data populated by Discord is removed so that the builder can be built
+    /// and sent again: the proxied URLs and dimensions of the image and
+    /// thumbnail, the proxied icon URLs of the author and footer, the
+    /// provider, and the video. The type is reset, and becomes "rich" when
+    /// built.
+    fn from(embed: Embed) -> Self {
+        Self(
+            Embed {
+                author: embed
+                    .author
+                    .map(|author| EmbedAuthorBuilder::from(author).build()),
+                color: embed.color,
+                description: embed.description,
+                fields: embed.fields,
+                footer: embed
+                    .footer
+                    .map(|footer| EmbedFooterBuilder::from(footer).build()),
+                image: embed.image.map(|image| EmbedImage {
+                    height: None,
+                    proxy_url: None,
+                    url: image.url,
+                    width: None,
+                }),
+                kind: String::new(),
+                provider: None,
+                thumbnail: embed.thumbnail.map(|thumbnail| EmbedThumbnail {
+                    height: None,
+                    proxy_url: None,
+                    url: thumbnail.url,
+                    width: None,
+                }),
+                timestamp: embed.timestamp,
+                title: embed.title,
+                url: embed.url,
+                video: None,
+            },
+            LengthCounting::default(),
+        )
+    }
+}
+
+/// Embed in the shape that builders are serialized as, excluding the type and
+/// the data populated by Discord.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize, serde::Serialize)]
+struct EmbedData<Author, Field, Footer, Image, Text> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<Author>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Text>,
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<Field>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    footer: Option<Footer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<EmbedImageData<Image>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumbnail: Option<EmbedImageData<Image>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<Text>,
+}
+
+/// Image or thumbnail in the shape that builders are serialized as.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize, serde::Serialize)]
+struct EmbedImageData<Image> {
+    url: Image,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EmbedBuilder {
+    /// Deserialize an embed builder, rejecting image, thumbnail and icon URLs
+    /// that aren't valid image sources.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = <EmbedData<
+            EmbedAuthorBuilder,
+            EmbedField,
+            EmbedFooterBuilder,
+            ImageSource,
+            String,
+        > as serde::Deserialize<'de>>::deserialize(deserializer)?;
+
+        Ok(Self(
+            Embed {
+                author: data.author.map(EmbedAuthorBuilder::build),
+                color: data.color,
+                description: data.description,
+                fields: data.fields,
+                footer: data.footer.map(EmbedFooterBuilder::build),
+                image: data.image.map(|image| EmbedImage {
+                    height: None,
+                    proxy_url: None,
+                    url: image.url.0,
+                    width: None,
+                }),
+                kind: String::new(),
+                provider: None,
+                thumbnail: data.thumbnail.map(|thumbnail| EmbedThumbnail {
+                    height: None,
+                    proxy_url: None,
+                    url: thumbnail.url.0,
+                    width: None,
+                }),
+                timestamp: data.timestamp,
+                title: data.title,
+                url: data.url,
+                video: None,
+            },
+            LengthCounting::default(),
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for EmbedBuilder {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data = EmbedData {
+            author: self.0.author.as_ref(),
+            color: self.0.color,
+            description: self.0.description.as_deref(),
+            fields: self.0.fields.iter().collect(),
+            footer: self.0.footer.as_ref(),
+            image: self
+                .0
+                .image
+                .as_ref()
+                .map(|image| EmbedImageData { url: &image.url }),
+            thumbnail: self.0.thumbnail.as_ref().map(|thumbnail| EmbedImageData {
+                url: &thumbnail.url,
+            }),
+            timestamp: self.0.timestamp,
+            title: self.0.title.as_deref(),
+            url: self.0.url.as_deref(),
+        };
+
+        serde::Serialize::serialize(&data, serializer)
+    }
+}
+
 impl TryFrom<EmbedBuilder> for Embed {
     type Error = EmbedError;
 
@@ -743,12 +1605,18 @@ impl TryFrom<EmbedBuilder> for Embed {
 
 #[cfg(test)]
 mod tests {
-    use super::{EmbedBuilder, EmbedError, EmbedErrorType};
-    use crate::{field::EmbedFieldBuilder, footer::EmbedFooterBuilder, image_source::ImageSource};
+    use super::{EmbedBuilder, EmbedError, EmbedErrorType, EmbedValidationError};
+    use crate::{
+        author::EmbedAuthorBuilder, field::EmbedFieldBuilder, footer::EmbedFooterBuilder,
+        image_source::ImageSource, Color, EmbedPart, LengthCounting,
+    };
     use static_assertions::{assert_fields, assert_impl_all, const_assert};
     use std::{error::Error, fmt::Debug};
     use twilight_model::{
-        channel::embed::{Embed, EmbedField, EmbedFooter},
+        channel::embed::{
+            Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedProvider, EmbedThumbnail,
+            EmbedVideo,
+        },
         util::Timestamp,
     };
 
@@ -769,6 +1637,7 @@ mod tests {
     assert_fields!(EmbedErrorType::FieldValueEmpty: name, value);
     assert_fields!(EmbedErrorType::FieldValueTooLong: name, value);
     assert_impl_all!(EmbedError: Error, Send, Sync);
+    assert_impl_all!(EmbedValidationError: Error, Send, Sync);
     const_assert!(EmbedBuilder::AUTHOR_NAME_LENGTH_LIMIT == 256);
     const_assert!(EmbedBuilder::COLOR_MAXIMUM == 0xff_ff_ff);
     const_assert!(EmbedBuilder::DESCRIPTION_LENGTH_LIMIT == 4096);
@@ -780,15 +1649,21 @@ mod tests {
     const_assert!(EmbedBuilder::TITLE_LENGTH_LIMIT == 256);
     assert_impl_all!(EmbedBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
     assert_impl_all!(Embed: TryFrom<EmbedBuilder>);
+    assert_impl_all!(EmbedBuilder: From<Embed>);
 
     #[test]
     fn color_error() {
+        let mut builder = EmbedBuilder::new();
+        builder.0.color = Some(0);
         assert!(matches!(
-            EmbedBuilder::new().color(0).build().unwrap_err().kind(),
+            builder.build().unwrap_err().kind(),
             EmbedErrorType::ColorZero
         ));
+
+        let mut builder = EmbedBuilder::new();
+        builder.0.color = Some(u32::MAX);
         assert!(matches!(
-            EmbedBuilder::new().color(u32::MAX).build().unwrap_err().kind(),
+            builder.build().unwrap_err().kind(),
             EmbedErrorType::ColorNotRgb { color }
             if *color == u32::MAX
         ));
@@ -824,6 +1699,89 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn blank_error() {
+        assert!(matches!(
+            EmbedBuilder::new().title("   ").build().unwrap_err().kind(),
+            EmbedErrorType::TitleBlank { title }
+            if title == "   "
+        ));
+        assert!(matches!(
+            EmbedBuilder::new()
+                .description("\u{200b}\n\u{feff}")
+                .build()
+                .unwrap_err()
+                .kind(),
+            EmbedErrorType::DescriptionBlank { .. }
+        ));
+        assert!(matches!(
+            EmbedBuilder::new()
+                .author(EmbedAuthorBuilder::new("\u{3164}".to_owned()))
+                .build()
+                .unwrap_err()
+                .kind(),
+            EmbedErrorType::AuthorNameBlank { .. }
+        ));
+        assert!(matches!(
+            EmbedBuilder::new()
+                .footer(EmbedFooterBuilder::new("\t"))
+                .build()
+                .unwrap_err()
+                .kind(),
+            EmbedErrorType::FooterTextBlank { .. }
+        ));
+        assert!(EmbedBuilder::new().title(" a\u{200b}").build().is_ok());
+    }
+
+    #[test]
+    fn validate_collects_every_error() {
+        let mut builder = EmbedBuilder::new()
+            .description("")
+            .field(EmbedFieldBuilder::new("name", "value"))
+            .field(EmbedFieldBuilder::new("", "a".repeat(1025)))
+            .title("a".repeat(257));
+        builder.0.color = Some(0);
+
+        let error = builder.validate().unwrap_err();
+        let errors = error.errors();
+
+        assert_eq!(5, errors.len());
+        assert!(matches!(errors[0].kind(), EmbedErrorType::ColorZero));
+        assert!(matches!(
+            errors[1].kind(),
+            EmbedErrorType::DescriptionEmpty { .. }
+        ));
+        assert!(matches!(
+            errors[2].kind(),
+            EmbedErrorType::FieldNameEmpty { .. }
+        ));
+        assert_eq!(Some(1), errors[2].field_index());
+        assert!(matches!(
+            errors[3].kind(),
+            EmbedErrorType::FieldValueTooLong { .. }
+        ));
+        assert_eq!(Some(1), errors[3].field_index());
+        assert!(matches!(
+            errors[4].kind(),
+            EmbedErrorType::TitleTooLong { .. }
+        ));
+        assert_eq!(None, errors[4].field_index());
+    }
+
+    #[test]
+    fn validate_matches_build() {
+        assert!(EmbedBuilder::new().description("a").validate().is_ok());
+
+        let builder = EmbedBuilder::new()
+            .field(EmbedFieldBuilder::new("a", "b"))
+            .field(EmbedFieldBuilder::new("a", ""));
+        let first = builder.validate().unwrap_err().into_errors().remove(0);
+        let built = builder.build().unwrap_err();
+
+        assert_eq!(first.to_string(), built.to_string());
+        assert_eq!(first.field_index(), built.field_index());
+    }
+
     #[test]
     fn builder() {
         let footer_image = ImageSource::url(
@@ -833,7 +1791,7 @@ mod tests {
         let timestamp = Timestamp::from_secs(1_580_608_922).expect("non zero");
 
         let embed = EmbedBuilder::new()
-            .color(0x00_43_ff)
+            .color(Color::new(0x00_43_ff).unwrap())
             .description("Description")
             .timestamp(timestamp)
             .footer(EmbedFooterBuilder::new("Warn").icon_url(footer_image))
@@ -871,4 +1829,228 @@ mod tests {
 
         assert_eq!(embed, expected);
     }
+
+    #[test]
+    fn fields() {
+        let names = |builder: &EmbedBuilder| {
+            builder
+                .get_fields()
+                .iter()
+                .map(|field| field.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let builder = EmbedBuilder::new()
+            .fields(["a", "b", "c"].map(|name| EmbedFieldBuilder::new(name, "value")))
+            .insert_field(1, EmbedFieldBuilder::new("d", "value"));
+        assert_eq!(["a", "d", "b", "c"], names(&builder).as_slice());
+
+        let builder = builder.move_field(0, 3).swap_fields(0, 1);
+        assert_eq!(["b", "d", "c", "a"], names(&builder).as_slice());
+
+        let builder = builder
+            .remove_field(1)
+            .replace_field(0, EmbedFieldBuilder::new("e", "value").inline());
+        assert_eq!(["e", "c", "a"], names(&builder).as_slice());
+        assert!(builder.get_field(0).unwrap().inline);
+        assert!(builder.get_field(3).is_none());
+
+        let builder = builder.retain_fields(|field| field.inline);
+        assert_eq!(["e"], names(&builder).as_slice());
+        assert!(builder.clear_fields().get_fields().is_empty());
+    }
+
+    #[test]
+    fn remaining() {
+        let builder = EmbedBuilder::new()
+            .description("a".repeat(4000))
+            .field(EmbedFieldBuilder::new("name", "a".repeat(1000)))
+            .title("title");
+
+        assert_eq!(5009, builder.length());
+        assert_eq!(991, builder.remaining_length());
+        assert_eq!(24, builder.remaining_field_slots());
+        assert_eq!(96, builder.remaining_part_length(EmbedPart::Description));
+        assert_eq!(24, builder.remaining_part_length(EmbedPart::FieldValue(0)));
+        assert_eq!(991, builder.remaining_part_length(EmbedPart::FieldValue(1)));
+        assert_eq!(251, builder.remaining_part_length(EmbedPart::Title));
+    }
+
+    #[test]
+    fn length_counting() {
+        let title = "🦄".repeat(200);
+
+        assert!(EmbedBuilder::new().title(title.clone()).build().is_ok());
+        assert!(matches!(
+            EmbedBuilder::new()
+                .title(title.clone())
+                .length_counting(LengthCounting::Utf16)
+                .build()
+                .unwrap_err()
+                .kind(),
+            EmbedErrorType::TitleTooLong { .. }
+        ));
+
+        let builder = EmbedBuilder::new()
+            .description("👨\u{200d}👩\u{200d}👧\u{200d}👦")
+            .title(title);
+        assert_eq!(207, builder.length());
+        assert_eq!(
+            201,
+            builder
+                .clone()
+                .length_counting(LengthCounting::Graphemes)
+                .length()
+        );
+
+        let builder = builder.length_counting(LengthCounting::Utf16);
+        assert_eq!(LengthCounting::Utf16, builder.get_length_counting());
+        assert_eq!(411, builder.length());
+        assert_eq!(4085, builder.remaining_part_length(EmbedPart::Description));
+
+        let (embed, truncations) = builder.build_truncated("…").unwrap();
+        // 127 unicorns of two code units each and the ellipsis.
+        assert_eq!(255, embed.title.unwrap().encode_utf16().count());
+        assert_eq!(EmbedPart::Title, truncations[0].part());
+    }
+
+    #[test]
+    fn from_embed() {
+        let timestamp = Timestamp::from_secs(1_580_608_922).expect("non zero");
+
+        let received = Embed {
+            author: Some(EmbedAuthor {
+                icon_url: Some("https://example.com/author.png".to_owned()),
+                name: "author".to_owned(),
+                proxy_icon_url: Some("https://images-ext-1.discordapp.net/author.png".to_owned()),
+                url: Some("https://example.com".to_owned()),
+            }),
+            color: Some(0x00_43_ff),
+            description: Some("description".to_owned()),
+            fields: vec![EmbedField {
+                inline: true,
+                name: "name".to_owned(),
+                value: "value".to_owned(),
+            }],
+            footer: Some(EmbedFooter {
+                icon_url: Some("https://example.com/footer.png".to_owned()),
+                proxy_icon_url: Some("https://images-ext-1.discordapp.net/footer.png".to_owned()),
+                text: "footer".to_owned(),
+            }),
+            image: Some(EmbedImage {
+                height: Some(128),
+                proxy_url: Some("https://images-ext-1.discordapp.net/image.png".to_owned()),
+                url: "https://example.com/image.png".to_owned(),
+                width: Some(128),
+            }),
+            kind: "rich".to_owned(),
+            provider: Some(EmbedProvider {
+                name: Some("provider".to_owned()),
+                url: None,
+            }),
+            thumbnail: Some(EmbedThumbnail {
+                height: Some(64),
+                proxy_url: Some("https://images-ext-1.discordapp.net/thumbnail.png".to_owned()),
+                url: "https://example.com/thumbnail.png".to_owned(),
+                width: Some(64),
+            }),
+            timestamp: Some(timestamp),
+            title: Some("title".to_owned()),
+            url: Some("https://example.com".to_owned()),
+            video: Some(EmbedVideo {
+                height: None,
+                proxy_url: None,
+                url: Some("https://example.com/video.mp4".to_owned()),
+                width: None,
+            }),
+        };
+
+        let expected = Embed {
+            author: Some(EmbedAuthor {
+                proxy_icon_url: None,
+                ..received.author.clone().unwrap()
+            }),
+            footer: Some(EmbedFooter {
+                proxy_icon_url: None,
+                ..received.footer.clone().unwrap()
+            }),
+            image: Some(EmbedImage {
+                height: None,
+                proxy_url: None,
+                url: "https://example.com/image.png".to_owned(),
+                width: None,
+            }),
+            provider: None,
+            thumbnail: Some(EmbedThumbnail {
+                height: None,
+                proxy_url: None,
+                url: "https://example.com/thumbnail.png".to_owned(),
+                width: None,
+            }),
+            video: None,
+            ..received.clone()
+        };
+
+        assert_eq!(expected, EmbedBuilder::from(received).build().unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> Result<(), Box<dyn Error>> {
+        use serde_json::json;
+
+        let builder = EmbedBuilder::new()
+            .author(
+                EmbedAuthorBuilder::new("author".to_owned())
+                    .icon_url(ImageSource::attachment("icon.png")?),
+            )
+            .color(Color::BLURPLE)
+            .field(EmbedFieldBuilder::new("name", "value").inline())
+            .image(ImageSource::url("https://example.com/image.png")?)
+            .title("title");
+        let value = json!({
+            "author": {
+                "icon_url": "attachment://icon.png",
+                "name": "author",
+            },
+            "color": 0x58_65_f2,
+            "fields": [{
+                "inline": true,
+                "name": "name",
+                "value": "value",
+            }],
+            "image": {
+                "url": "https://example.com/image.png",
+            },
+            "title": "title",
+        });
+
+        assert_eq!(value, serde_json::to_value(&builder)?);
+        assert_eq!(builder, serde_json::from_value(value)?);
+
+        let received = json!({
+            "description": "description",
+            "thumbnail": {
+                "proxy_url": "https://proxy.example.com/thumbnail.png",
+                "url": "https://example.com/thumbnail.png",
+                "width": 100,
+            },
+            "type": "rich",
+        });
+        let expected = EmbedBuilder::new()
+            .description("description")
+            .thumbnail(ImageSource::url("https://example.com/thumbnail.png")?);
+        assert_eq!(expected, serde_json::from_value(received)?);
+
+        for invalid in [
+            json!({ "image": { "url": "ftp://example.com/image.png" } }),
+            json!({ "thumbnail": { "url": "attachment://thumbnail" } }),
+            json!({ "author": { "icon_url": "attachment://icon.", "name": "a" } }),
+            json!({ "footer": { "icon_url": "file:///icon.png", "text": "a" } }),
+        ] {
+            assert!(serde_json::from_value::<EmbedBuilder>(invalid).is_err());
+        }
+
+        Ok(())
+    }
 }
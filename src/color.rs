@@ -0,0 +1,504 @@
+//! Colors of embeds.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+/// Error creating a color.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct ColorError {
+    kind: ColorErrorType,
+}
+
+impl ColorError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ColorErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ColorErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ColorErrorType::NotRgb { color } => {
+                f.write_str("the color ")?;
+                Display::fmt(color, f)?;
+
+                f.write_str(" is larger than a valid RGB value")
+            }
+            ColorErrorType::Zero => {
+                f.write_str("the given color value is 0, which is not acceptable")
+            }
+        }
+    }
+}
+
+impl Error for ColorError {}
+
+/// Type of [`ColorError`] that occurred.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ColorErrorType {
+    /// Color was larger than a valid RGB hexadecimal value.
+    ///
+    /// Refer to [`Color::MAXIMUM`] for the maximum value.
+    NotRgb {
+        /// Provided color value.
+        color: u32,
+    },
+    /// Color was 0, or black. The value would be thrown out by Discord and is
+    /// equivalent to null.
+    Zero,
+}
+
+/// Error parsing a color from a hexadecimal string.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct ColorParseError {
+    kind: ColorParseErrorType,
+}
+
+impl ColorParseError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ColorParseErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ColorParseErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ColorParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ColorParseErrorType::DigitInvalid { color } => {
+                f.write_str("the color ")?;
+                f.write_str(color)?;
+
+                f.write_str(" contains a character that isn't a hexadecimal digit")
+            }
+            ColorParseErrorType::LengthInvalid { color } => {
+                f.write_str("the color ")?;
+                f.write_str(color)?;
+
+                f.write_str(" isn't 3 or 6 hexadecimal digits long")
+            }
+            ColorParseErrorType::Zero => {
+                f.write_str("the given color is black, which is not acceptable")
+            }
+        }
+    }
+}
+
+impl Error for ColorParseError {}
+
+/// Type of [`ColorParseError`] that occurred.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ColorParseErrorType {
+    /// Color contains a character that isn't a hexadecimal digit.
+    DigitInvalid {
+        /// Provided color.
+        color: String,
+    },
+    /// Color isn't 3 or 6 hexadecimal digits long, excluding the optional
+    /// leading `#`.
+    LengthInvalid {
+        /// Provided color.
+        color: String,
+    },
+    /// Color is black. The value would be thrown out by Discord and is
+    /// equivalent to null.
+    Zero,
+}
+
+/// Color of an embed.
+///
+/// A color is always a valid, non-zero RGB value, so it can't cause an
+/// embed to fail to build.
+///
+/// # Examples
+///
+/// Create colors from RGB components, a hexadecimal string and a named
+/// constant:
+///
+/// ```
+/// use twilight_embed_builder::Color;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let rgb = Color::from_rgb(0x58, 0x65, 0xf2)?;
+/// let hex = "#5865f2".parse::<Color>()?;
+///
+/// assert_eq!(Color::BLURPLE, rgb);
+/// assert_eq!(Color::BLURPLE, hex);
+/// assert_eq!("#5865f2", Color::BLURPLE.to_string());
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Color(u32);
+
+impl Color {
+    /// The maximum accepted color value.
+    pub const MAXIMUM: u32 = 0xff_ff_ff;
+
+    /// Aqua, `#1abc9c`.
+    pub const AQUA: Self = Self(0x1a_bc_9c);
+
+    /// Blue, `#3498db`.
+    pub const BLUE: Self = Self(0x34_98_db);
+
+    /// Discord's blurple brand color, `#5865f2`.
+    pub const BLURPLE: Self = Self(0x58_65_f2);
+
+    /// Discord's fuchsia brand color, `#eb459e`.
+    pub const FUCHSIA: Self = Self(0xeb_45_9e);
+
+    /// Gold, `#f1c40f`.
+    pub const GOLD: Self = Self(0xf1_c4_0f);
+
+    /// Discord's green brand color, `#57f287`.
+    pub const GREEN: Self = Self(0x57_f2_87);
+
+    /// Grey, `#95a5a6`.
+    pub const GREY: Self = Self(0x95_a5_a6);
+
+    /// Discord's legacy greyple color, `#99aab5`.
+    pub const GREYPLE: Self = Self(0x99_aa_b5);
+
+    /// Navy, `#34495e`.
+    pub const NAVY: Self = Self(0x34_49_5e);
+
+    /// Discord's black brand color, `#23272a`.
+    ///
+    /// This is as close to black as an embed color can meaningfully be.
+    pub const NOT_QUITE_BLACK: Self = Self(0x23_27_2a);
+
+    /// Discord's legacy blurple color, `#7289da`.
+    pub const OLD_BLURPLE: Self = Self(0x72_89_da);
+
+    /// Orange, `#e67e22`.
+    pub const ORANGE: Self = Self(0xe6_7e_22);
+
+    /// Purple, `#9b59b6`.
+    pub const PURPLE: Self = Self(0x9b_59_b6);
+
+    /// Discord's red brand color, `#ed4245`.
+    pub const RED: Self = Self(0xed_42_45);
+
+    /// Discord's white brand color, `#ffffff`.
+    pub const WHITE: Self = Self(0xff_ff_ff);
+
+    /// Discord's yellow brand color, `#fee75c`.
+    pub const YELLOW: Self = Self(0xfe_e7_5c);
+
+    /// Create a color from a hexadecimal RGB value, such as `0xfd69b3`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ColorErrorType::NotRgb`] error type if the value is larger
+    /// than [`MAXIMUM`].
+    ///
+    /// Returns a [`ColorErrorType::Zero`] error type if the value is 0.
+    ///
+    /// [`MAXIMUM`]: Self::MAXIMUM
+    pub const fn new(color: u32) -> Result<Self, ColorError> {
+        if color == 0 {
+            return Err(ColorError {
+                kind: ColorErrorType::Zero,
+            });
+        }
+
+        if color > Self::MAXIMUM {
+            return Err(ColorError {
+                kind: ColorErrorType::NotRgb { color },
+            });
+        }
+
+        Ok(Self(color))
+    }
+
+    /// Create a color from its red, green and blue components.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ColorErrorType::Zero`] error type if every component is 0.
+    pub const fn from_rgb(red: u8, green: u8, blue: u8) -> Result<Self, ColorError> {
+        Self::new(((red as u32) << 16) | ((green as u32) << 8) | blue as u32)
+    }
+
+    /// Create a color from its hue in degrees, and its saturation and
+    /// lightness between 0 and 1.
+    ///
+    /// The hue wraps around, and the saturation and lightness are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_embed_builder::Color;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(Color::from_rgb(0xff, 0, 0)?, Color::from_hsl(0.0, 1.0, 0.5)?);
+    /// assert_eq!(Color::from_rgb(0, 0xff, 0)?, Color::from_hsl(480.0, 1.0, 0.5)?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ColorErrorType::Zero`] error type if the color is black.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::many_single_char_names
+    )]
+    pub fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Result<Self, ColorError> {
+        let h = hue.rem_euclid(360.0) / 60.0;
+        let s = saturation.clamp(0.0, 1.0);
+        let l = lightness.clamp(0.0, 1.0);
+
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let m = l - chroma / 2.0;
+
+        let (r, g, b) = match h as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let component = |value: f64| ((value + m) * 255.0).round() as u8;
+
+        Self::from_rgb(component(r), component(g), component(b))
+    }
+
+    /// Hexadecimal RGB value of the color.
+    #[must_use = "retrieving the value has no effect if left unused"]
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    /// Red component of the color.
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use = "retrieving the component has no effect if left unused"]
+    pub const fn red(self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// Green component of the color.
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use = "retrieving the component has no effect if left unused"]
+    pub const fn green(self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Blue component of the color.
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use = "retrieving the component has no effect if left unused"]
+    pub const fn blue(self) -> u8 {
+        self.0 as u8
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "#{:06x}", self.0)
+    }
+}
+
+impl From<Color> for u32 {
+    fn from(color: Color) -> Self {
+        color.get()
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    /// Parse a color from a hexadecimal string in the form of `#rrggbb` or
+    /// the shorthand `#rgb`. The leading `#` is optional.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix('#').unwrap_or(s);
+
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ColorParseError {
+                kind: ColorParseErrorType::DigitInvalid {
+                    color: s.to_owned(),
+                },
+            });
+        }
+
+        let value = match digits.len() {
+            3 => digits
+                .chars()
+                .filter_map(|c| c.to_digit(16))
+                .fold(0, |value, digit| (value << 8) | (digit * 0x11)),
+            6 => u32::from_str_radix(digits, 16).unwrap_or_default(),
+            _ => {
+                return Err(ColorParseError {
+                    kind: ColorParseErrorType::LengthInvalid {
+                        color: s.to_owned(),
+                    },
+                })
+            }
+        };
+
+        Self::new(value).map_err(|_| ColorParseError {
+            kind: ColorParseErrorType::Zero,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    /// Deserialize a color from its hexadecimal RGB value, which must be valid
+    /// for [`Color::new`].
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::new(u32::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, ColorError, ColorErrorType, ColorParseError, ColorParseErrorType};
+    use static_assertions::{assert_fields, assert_impl_all, const_assert};
+    use std::{error::Error, fmt::Debug, hash::Hash, str::FromStr};
+
+    assert_impl_all!(ColorErrorType: Debug, Send, Sync);
+    assert_fields!(ColorErrorType::NotRgb: color);
+    assert_impl_all!(ColorError: Error, Send, Sync);
+    assert_impl_all!(ColorParseErrorType: Debug, Send, Sync);
+    assert_fields!(ColorParseErrorType::DigitInvalid: color);
+    assert_fields!(ColorParseErrorType::LengthInvalid: color);
+    assert_impl_all!(ColorParseError: Error, Send, Sync);
+    assert_impl_all!(
+        Color: Clone,
+        Copy,
+        Debug,
+        Eq,
+        FromStr,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+    const_assert!(Color::MAXIMUM == 0xff_ff_ff);
+
+    #[test]
+    fn new() {
+        assert!(matches!(
+            Color::new(0).unwrap_err().kind(),
+            ColorErrorType::Zero
+        ));
+        assert!(matches!(
+            Color::new(0x01_00_00_00).unwrap_err().kind(),
+            ColorErrorType::NotRgb {
+                color: 0x01_00_00_00
+            }
+        ));
+        assert_eq!(0xfd_69_b3, Color::new(0xfd_69_b3).unwrap().get());
+    }
+
+    #[test]
+    fn rgb() {
+        let color = Color::from_rgb(0xfd, 0x69, 0xb3).unwrap();
+
+        assert_eq!(0xfd_69_b3, color.get());
+        assert_eq!(
+            (0xfd, 0x69, 0xb3),
+            (color.red(), color.green(), color.blue())
+        );
+        assert!(Color::from_rgb(0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn hsl() {
+        assert_eq!(Color::WHITE, Color::from_hsl(0.0, 0.0, 1.0).unwrap());
+        assert_eq!(
+            Color::from_rgb(0, 0, 0xff).unwrap(),
+            Color::from_hsl(-120.0, 1.0, 0.5).unwrap()
+        );
+        assert_eq!(
+            Color::from_rgb(0x80, 0x80, 0x80).unwrap(),
+            Color::from_hsl(200.0, 0.0, 0.5).unwrap()
+        );
+        assert!(Color::from_hsl(0.0, 1.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Color::BLURPLE, "#5865f2".parse().unwrap());
+        assert_eq!(Color::BLURPLE, "5865F2".parse().unwrap());
+        assert_eq!(
+            Color::from_rgb(0xff, 0x66, 0x00).unwrap(),
+            "#f60".parse().unwrap()
+        );
+        assert!(matches!(
+            "#12345g".parse::<Color>().unwrap_err().kind(),
+            ColorParseErrorType::DigitInvalid { color } if color == "#12345g"
+        ));
+        assert!(matches!(
+            "#1234".parse::<Color>().unwrap_err().kind(),
+            ColorParseErrorType::LengthInvalid { .. }
+        ));
+        assert!(matches!(
+            "#000".parse::<Color>().unwrap_err().kind(),
+            ColorParseErrorType::Zero
+        ));
+        assert!("#+12345".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("#00ff00", Color::from_rgb(0, 0xff, 0).unwrap().to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> Result<(), Box<dyn Error>> {
+        assert_eq!("15548997", serde_json::to_string(&Color::RED)?);
+        assert_eq!(Color::RED, serde_json::from_str("15548997")?);
+        assert!(serde_json::from_str::<Color>("0").is_err());
+        assert!(serde_json::from_str::<Color>("16777216").is_err());
+
+        Ok(())
+    }
+}
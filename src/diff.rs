@@ -0,0 +1,647 @@
+//! Compare embeds to find what changed between them.
+
+use crate::as_embed::AsEmbed;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    slice::Iter,
+    vec::IntoIter,
+};
+use twilight_model::{
+    channel::embed::{Embed, EmbedAuthor, EmbedField, EmbedFooter},
+    util::Timestamp,
+};
+
+/// Change between two embeds.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Change {
+    /// The author was added, removed or changed.
+    Author {
+        /// New author.
+        new: Option<EmbedAuthor>,
+        /// Previous author.
+        old: Option<EmbedAuthor>,
+    },
+    /// The color was added, removed or changed.
+    Color {
+        /// New color.
+        new: Option<u32>,
+        /// Previous color.
+        old: Option<u32>,
+    },
+    /// The description was added, removed or changed.
+    Description {
+        /// New description.
+        new: Option<String>,
+        /// Previous description.
+        old: Option<String>,
+    },
+    /// A field was added.
+    FieldAdded {
+        /// Added field.
+        field: EmbedField,
+        /// Index of the field in the new embed.
+        index: usize,
+    },
+    /// The name, value or inline status of a field changed.
+    ///
+    /// Fields are matched by name, and fields whose name changed are matched
+    /// by position.
+    FieldChanged {
+        /// Index of the field in the new embed.
+        index: usize,
+        /// New field.
+        new: EmbedField,
+        /// Previous field.
+        old: EmbedField,
+    },
+    /// A field moved relative to the other fields.
+    ///
+    /// Fields shifting because other fields were added or removed aren't
+    /// considered to have moved.
+    FieldMoved {
+        /// Index of the field in the previous embed.
+        from: usize,
+        /// Name of the field in the new embed.
+        name: String,
+        /// Index of the field in the new embed.
+        to: usize,
+    },
+    /// A field was removed.
+    FieldRemoved {
+        /// Removed field.
+        field: EmbedField,
+        /// Index of the field in the previous embed.
+        index: usize,
+    },
+    /// The footer was added, removed or changed.
+    Footer {
+        /// New footer.
+        new: Option<EmbedFooter>,
+        /// Previous footer.
+        old: Option<EmbedFooter>,
+    },
+    /// The URL of the image was added, removed or changed.
+    Image {
+        /// New URL of the image.
+        new: Option<String>,
+        /// Previous URL of the image.
+        old: Option<String>,
+    },
+    /// The URL of the thumbnail was added, removed or changed.
+    Thumbnail {
+        /// New URL of the thumbnail.
+        new: Option<String>,
+        /// Previous URL of the thumbnail.
+        old: Option<String>,
+    },
+    /// The timestamp was added, removed or changed.
+    Timestamp {
+        /// New timestamp.
+        new: Option<Timestamp>,
+        /// Previous timestamp.
+        old: Option<Timestamp>,
+    },
+    /// The title was added, removed or changed.
+    Title {
+        /// New title.
+        new: Option<String>,
+        /// Previous title.
+        old: Option<String>,
+    },
+    /// The URL of the title was added, removed or changed.
+    Url {
+        /// New URL.
+        new: Option<String>,
+        /// Previous URL.
+        old: Option<String>,
+    },
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Author { new, old } => part(
+                f,
+                "author",
+                old.as_ref().map(describe_author),
+                new.as_ref().map(describe_author),
+            ),
+            Self::Color { new, old } => part(
+                f,
+                "color",
+                old.map(|color| format!("#{color:06x}")),
+                new.map(|color| format!("#{color:06x}")),
+            ),
+            Self::Description { new, old } => part(
+                f,
+                "description",
+                quote(old.as_deref()),
+                quote(new.as_deref()),
+            ),
+            Self::FieldAdded { field, index } => {
+                write!(f, "field {index} added: {}", describe_field(field))
+            }
+            Self::FieldChanged { index, new, old } => write!(
+                f,
+                "field {index} changed: {} -> {}",
+                describe_field(old),
+                describe_field(new)
+            ),
+            Self::FieldMoved { from, name, to } => {
+                write!(f, "field {name:?} moved from {from} to {to}")
+            }
+            Self::FieldRemoved { field, index } => {
+                write!(f, "field {index} removed: {}", describe_field(field))
+            }
+            Self::Footer { new, old } => part(
+                f,
+                "footer",
+                old.as_ref().map(describe_footer),
+                new.as_ref().map(describe_footer),
+            ),
+            Self::Image { new, old } => {
+                part(f, "image", quote(old.as_deref()), quote(new.as_deref()))
+            }
+            Self::Thumbnail { new, old } => {
+                part(f, "thumbnail", quote(old.as_deref()), quote(new.as_deref()))
+            }
+            Self::Timestamp { new, old } => part(
+                f,
+                "timestamp",
+                old.map(|timestamp| timestamp.iso_8601().to_string()),
+                new.map(|timestamp| timestamp.iso_8601().to_string()),
+            ),
+            Self::Title { new, old } => {
+                part(f, "title", quote(old.as_deref()), quote(new.as_deref()))
+            }
+            Self::Url { new, old } => part(f, "url", quote(old.as_deref()), quote(new.as_deref())),
+        }
+    }
+}
+
+/// Changes between two embeds.
+///
+/// Only the parts of embeds that can be set are compared, so the proxy URLs
+/// and dimensions that Discord adds to images and icons don't count as
+/// changes. Changes are in the order that the parts are displayed in.
+///
+/// # Examples
+///
+/// Skip editing a message when the embed wouldn't change:
+///
+/// ```
+/// use twilight_embed_builder::{EmbedBuilder, EmbedDiff, EmbedFieldBuilder};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let current = EmbedBuilder::new()
+///     .title("Server status")
+///     .field(EmbedFieldBuilder::new("Players", "12"))
+///     .build()?;
+/// let updated = EmbedBuilder::new()
+///     .title("Server status")
+///     .field(EmbedFieldBuilder::new("Players", "13"));
+///
+/// let diff = EmbedDiff::new(&current, &updated);
+///
+/// assert!(!diff.is_empty());
+/// assert_eq!(
+///     r#"field 0 changed: "Players" = "12" -> "Players" = "13""#,
+///     diff.to_string(),
+/// );
+/// # Ok(()) }
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[must_use = "changes must be retrieved from the diff"]
+pub struct EmbedDiff(Vec<Change>);
+
+impl EmbedDiff {
+    /// Compare an embed with a new version of it.
+    ///
+    /// Accepts both built embeds and builders.
+    pub fn new(old: &impl AsEmbed, new: &impl AsEmbed) -> Self {
+        let (old, new) = (old.as_embed(), new.as_embed());
+        let mut changes = Vec::new();
+
+        if old.color != new.color {
+            changes.push(Change::Color {
+                new: new.color,
+                old: old.color,
+            });
+        }
+
+        if !same_author(old.author.as_ref(), new.author.as_ref()) {
+            changes.push(Change::Author {
+                new: new.author.clone(),
+                old: old.author.clone(),
+            });
+        }
+
+        if old.title != new.title {
+            changes.push(Change::Title {
+                new: new.title.clone(),
+                old: old.title.clone(),
+            });
+        }
+
+        if old.url != new.url {
+            changes.push(Change::Url {
+                new: new.url.clone(),
+                old: old.url.clone(),
+            });
+        }
+
+        if old.description != new.description {
+            changes.push(Change::Description {
+                new: new.description.clone(),
+                old: old.description.clone(),
+            });
+        }
+
+        fields(&old.fields, &new.fields, &mut changes);
+
+        let image = |embed: &Embed| embed.image.as_ref().map(|image| image.url.clone());
+
+        if image(old) != image(new) {
+            changes.push(Change::Image {
+                new: image(new),
+                old: image(old),
+            });
+        }
+
+        let thumbnail = |embed: &Embed| {
+            embed
+                .thumbnail
+                .as_ref()
+                .map(|thumbnail| thumbnail.url.clone())
+        };
+
+        if thumbnail(old) != thumbnail(new) {
+            changes.push(Change::Thumbnail {
+                new: thumbnail(new),
+                old: thumbnail(old),
+            });
+        }
+
+        if !same_footer(old.footer.as_ref(), new.footer.as_ref()) {
+            changes.push(Change::Footer {
+                new: new.footer.clone(),
+                old: old.footer.clone(),
+            });
+        }
+
+        if old.timestamp != new.timestamp {
+            changes.push(Change::Timestamp {
+                new: new.timestamp,
+                old: old.timestamp,
+            });
+        }
+
+        Self(changes)
+    }
+
+    /// Immutable reference to the changes.
+    #[must_use = "retrieving the changes has no effect if left unused"]
+    pub fn changes(&self) -> &[Change] {
+        &self.0
+    }
+
+    /// Consume the diff, returning its changes.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "consuming the diff into its changes has no effect if left unused"]
+    pub fn into_changes(self) -> Vec<Change> {
+        self.0
+    }
+
+    /// Whether the embeds are the same.
+    #[must_use = "retrieving whether there are changes has no effect if left unused"]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterator over the changes.
+    #[must_use = "iterating over the changes has no effect if left unused"]
+    pub fn iter(&self) -> Iter<'_, Change> {
+        self.0.iter()
+    }
+
+    /// Number of changes.
+    #[must_use = "retrieving the number of changes has no effect if left unused"]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// Write each change on its own line.
+impl Display for EmbedDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (idx, change) in self.0.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("\n")?;
+            }
+
+            Display::fmt(change, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl IntoIterator for EmbedDiff {
+    type Item = Change;
+    type IntoIter = IntoIter<Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a EmbedDiff {
+    type Item = &'a Change;
+    type IntoIter = Iter<'a, Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Describe an author by its name, URL and icon.
+fn describe_author(author: &EmbedAuthor) -> String {
+    let mut description = format!("{:?}", author.name);
+
+    if let Some(url) = &author.url {
+        let _ = write!(description, " <{url}>");
+    }
+
+    if let Some(icon_url) = &author.icon_url {
+        let _ = write!(description, " (icon {icon_url})");
+    }
+
+    description
+}
+
+/// Describe a field by its name and value, and whether it's inline.
+fn describe_field(field: &EmbedField) -> String {
+    let inline = if field.inline { " (inline)" } else { "" };
+
+    format!("{:?} = {:?}{inline}", field.name, field.value)
+}
+
+/// Describe a footer by its text and icon.
+fn describe_footer(footer: &EmbedFooter) -> String {
+    match &footer.icon_url {
+        Some(icon_url) => format!("{:?} (icon {icon_url})", footer.text),
+        None => format!("{:?}", footer.text),
+    }
+}
+
+/// Compare fields, matching them by name and otherwise by position.
+fn fields(old: &[EmbedField], new: &[EmbedField], changes: &mut Vec<Change>) {
+    let mut matched_old = vec![None; old.len()];
+    let mut matched_new = vec![None; new.len()];
+
+    for (new_idx, field) in new.iter().enumerate() {
+        let old_idx = old
+            .iter()
+            .zip(&matched_old)
+            .position(|(old_field, matched)| matched.is_none() && old_field.name == field.name);
+
+        if let Some(old_idx) = old_idx {
+            matched_old[old_idx] = Some(new_idx);
+            matched_new[new_idx] = Some(old_idx);
+        }
+    }
+
+    // Fields that were renamed are matched to the field at their position.
+    for idx in 0..old.len().min(new.len()) {
+        if matched_old[idx].is_none() && matched_new[idx].is_none() {
+            matched_old[idx] = Some(idx);
+            matched_new[idx] = Some(idx);
+        }
+    }
+
+    for (index, field) in old.iter().enumerate() {
+        if matched_old[index].is_none() {
+            changes.push(Change::FieldRemoved {
+                field: field.clone(),
+                index,
+            });
+        }
+    }
+
+    let stable = in_order(&matched_new);
+
+    for (index, field) in new.iter().enumerate() {
+        let old_idx = if let Some(old_idx) = matched_new[index] {
+            old_idx
+        } else {
+            changes.push(Change::FieldAdded {
+                field: field.clone(),
+                index,
+            });
+
+            continue;
+        };
+
+        if !stable[index] {
+            changes.push(Change::FieldMoved {
+                from: old_idx,
+                name: field.name.clone(),
+                to: index,
+            });
+        }
+
+        if old[old_idx] != *field {
+            changes.push(Change::FieldChanged {
+                index,
+                new: field.clone(),
+                old: old[old_idx].clone(),
+            });
+        }
+    }
+}
+
+/// Mark the matched fields that kept their order relative to each other.
+///
+/// This is the longest sequence of fields whose previous positions are
+/// increasing, so that the fewest fields are considered to have moved.
+fn in_order(matched: &[Option<usize>]) -> Vec<bool> {
+    let mut lengths = vec![0_usize; matched.len()];
+    let mut previous = vec![None; matched.len()];
+
+    for (idx, position) in matched.iter().enumerate() {
+        let position = match position {
+            Some(position) => position,
+            None => continue,
+        };
+
+        lengths[idx] = 1;
+
+        for (earlier, earlier_position) in matched[..idx].iter().enumerate() {
+            if earlier_position.map_or(false, |earlier_position| earlier_position < *position)
+                && lengths[earlier] + 1 > lengths[idx]
+            {
+                lengths[idx] = lengths[earlier] + 1;
+                previous[idx] = Some(earlier);
+            }
+        }
+    }
+
+    let mut stable = vec![false; matched.len()];
+    let mut current = (0..matched.len())
+        .max_by_key(|idx| lengths[*idx])
+        .filter(|idx| lengths[*idx] > 0);
+
+    while let Some(idx) = current {
+        stable[idx] = true;
+        current = previous[idx];
+    }
+
+    stable
+}
+
+/// Write a change to a part that may be added or removed.
+fn part(f: &mut Formatter<'_>, name: &str, old: Option<String>, new: Option<String>) -> FmtResult {
+    match (old, new) {
+        (None, Some(new)) => write!(f, "{name} added: {new}"),
+        (Some(old), None) => write!(f, "{name} removed: {old}"),
+        (Some(old), Some(new)) => write!(f, "{name} changed: {old} -> {new}"),
+        (None, None) => write!(f, "{name} unchanged"),
+    }
+}
+
+/// Quote text for display.
+fn quote(text: Option<&str>) -> Option<String> {
+    text.map(|text| format!("{text:?}"))
+}
+
+/// Whether two authors are the same, ignoring the proxied URL of their icons.
+fn same_author(old: Option<&EmbedAuthor>, new: Option<&EmbedAuthor>) -> bool {
+    match (old, new) {
+        (Some(old), Some(new)) => {
+            old.icon_url == new.icon_url && old.name == new.name && old.url == new.url
+        }
+        (old, new) => old.is_none() && new.is_none(),
+    }
+}
+
+/// Whether two footers are the same, ignoring the proxied URL of their icons.
+fn same_footer(old: Option<&EmbedFooter>, new: Option<&EmbedFooter>) -> bool {
+    match (old, new) {
+        (Some(old), Some(new)) => old.icon_url == new.icon_url && old.text == new.text,
+        (old, new) => old.is_none() && new.is_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Change, EmbedDiff};
+    use crate::{Color, EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder, ImageSource};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::channel::embed::{Embed, EmbedImage};
+
+    assert_impl_all!(
+        EmbedDiff: Clone,
+        Debug,
+        Default,
+        Eq,
+        IntoIterator,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_impl_all!(Change: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_fields!(Change::FieldAdded: field, index);
+    assert_fields!(Change::FieldChanged: index, new, old);
+    assert_fields!(Change::FieldMoved: from, name, to);
+    assert_fields!(Change::FieldRemoved: field, index);
+    assert_fields!(Change::Title: new, old);
+
+    fn embed(names: &[&str]) -> Embed {
+        names
+            .iter()
+            .fold(EmbedBuilder::new(), |builder, name| {
+                builder.field(EmbedFieldBuilder::new(*name, "value"))
+            })
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn same() -> Result<(), Box<dyn Error>> {
+        let builder = EmbedBuilder::new()
+            .title("title")
+            .image(ImageSource::url("https://example.com/image.png")?);
+        let mut embed = builder.clone().build()?;
+        embed.image = Some(EmbedImage {
+            height: Some(100),
+            proxy_url: Some("https://media.discordapp.net/image.png".to_owned()),
+            url: "https://example.com/image.png".to_owned(),
+            width: Some(100),
+        });
+
+        let diff = EmbedDiff::new(&builder, &embed);
+        assert!(diff.is_empty());
+        assert_eq!("", diff.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parts() -> Result<(), Box<dyn Error>> {
+        let old = EmbedBuilder::new()
+            .color(Color::new(0x00_00_ff)?)
+            .title("old")
+            .footer(EmbedFooterBuilder::new("footer"));
+        let new = EmbedBuilder::new()
+            .color(Color::new(0xff_00_00)?)
+            .description("description")
+            .thumbnail(ImageSource::url("https://example.com/thumbnail.png")?);
+        let diff = EmbedDiff::new(&old, &new);
+
+        assert_eq!(
+            "color changed: #0000ff -> #ff0000\n\
+            title removed: \"old\"\n\
+            description added: \"description\"\n\
+            thumbnail added: \"https://example.com/thumbnail.png\"\n\
+            footer removed: \"footer\"",
+            diff.to_string()
+        );
+        assert!(matches!(
+            diff.changes()[1],
+            Change::Title { new: None, ref old } if old.as_deref() == Some("old")
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fields() {
+        let diff = EmbedDiff::new(&embed(&["a", "b", "c", "d"]), &embed(&["x", "c", "a", "e"]));
+
+        assert_eq!(
+            vec![
+                "field 1 removed: \"b\" = \"value\"",
+                "field 0 added: \"x\" = \"value\"",
+                "field \"a\" moved from 0 to 2",
+                "field 3 changed: \"d\" = \"value\" -> \"e\" = \"value\"",
+            ],
+            diff.iter().map(ToString::to_string).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn shifted_fields_not_moved() {
+        let diff = EmbedDiff::new(&embed(&["a", "b"]), &embed(&["x", "a", "b"]));
+
+        assert_eq!(
+            vec![Change::FieldAdded {
+                field: EmbedFieldBuilder::new("x", "value").build(),
+                index: 0,
+            }],
+            diff.into_changes()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Create embed fields.
 
+use super::sanitize::{PartKind, Sanitizer};
 use twilight_model::channel::embed::EmbedField;
 
 /// Create an embed field with a builder.
@@ -11,17 +12,50 @@ use twilight_model::channel::embed::EmbedField;
 /// [`EmbedBuilder::field`]: crate::EmbedBuilder::field
 /// [`inline`]: Self::inline
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
 #[must_use = "must be built into an embed field"]
 pub struct EmbedFieldBuilder(EmbedField);
 
 impl EmbedFieldBuilder {
+    /// Text of a blank field, which is a zero width space.
+    ///
+    /// Field names and values must not be blank, so that embeds don't contain
+    /// fields by mistake. A name or value that is exactly this text is an
+    /// intentional exception, and is displayed by Discord as blank. This can
+    /// be used to space out inline fields, or to add a field without a name or
+    /// value.
+    ///
+    /// Refer to [`spacer`] to create a field that is entirely blank.
+    ///
+    /// # Examples
+    ///
+    /// Add a field without a name:
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedFieldBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let embed = EmbedBuilder::new()
+    ///     .field(EmbedFieldBuilder::new("Tags", "ponies, magic"))
+    ///     .field(EmbedFieldBuilder::new(EmbedFieldBuilder::SPACER, "friendship"))
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`spacer`]: Self::spacer
+    pub const SPACER: &'static str = "\u{200b}";
+
     /// Create a new default embed field builder.
     ///
     /// Refer to [`EmbedBuilder::FIELD_NAME_LENGTH_LIMIT`] for the maximum
-    /// number of UTF-16 code points that can be in a field name.
+    /// length of a field name.
     ///
     /// Refer to [`EmbedBuilder::FIELD_VALUE_LENGTH_LIMIT`] for the maximum
-    /// number of UTF-16 code points that can be in a field value.
+    /// length of a field value.
     ///
     /// [`EmbedBuilder::FIELD_NAME_LENGTH_LIMIT`]: crate::EmbedBuilder::FIELD_NAME_LENGTH_LIMIT
     /// [`EmbedBuilder::FIELD_VALUE_LENGTH_LIMIT`]: crate::EmbedBuilder::FIELD_VALUE_LENGTH_LIMIT
@@ -62,6 +96,53 @@ impl EmbedFieldBuilder {
 
         self
     }
+
+    /// Create a blank field, with a name and value of [`SPACER`].
+    ///
+    /// Blank fields are usually inlined to space out other inline fields.
+    ///
+    /// # Examples
+    ///
+    /// Lay out four inline fields as two rows of two, instead of a row of
+    /// three followed by a row of one:
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedFieldBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let embed = EmbedBuilder::new()
+    ///     .field(EmbedFieldBuilder::new("Wins", "12").inline())
+    ///     .field(EmbedFieldBuilder::new("Losses", "3").inline())
+    ///     .field(EmbedFieldBuilder::spacer().inline())
+    ///     .field(EmbedFieldBuilder::new("Draws", "1").inline())
+    ///     .field(EmbedFieldBuilder::new("Streak", "4").inline())
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`SPACER`]: Self::SPACER
+    pub fn spacer() -> Self {
+        Self::new(Self::SPACER, Self::SPACER)
+    }
+
+    /// Sanitize the field's name and value.
+    ///
+    /// Refer to [`Sanitizer`] for how text is sanitized.
+    ///
+    /// [`Sanitizer`]: crate::Sanitizer
+    pub fn sanitize(mut self, sanitizer: &Sanitizer) -> Self {
+        sanitizer.sanitize(PartKind::FieldName, &mut self.0.name);
+        sanitizer.sanitize(PartKind::FieldValue, &mut self.0.value);
+
+        self
+    }
+}
+
+impl From<EmbedField> for EmbedFieldBuilder {
+    /// Create an embed field builder from an existing embed field.
+    fn from(field: EmbedField) -> Self {
+        Self(field)
+    }
 }
 
 impl From<EmbedFieldBuilder> for EmbedField {
@@ -83,6 +164,7 @@ mod tests {
 
     assert_impl_all!(EmbedFieldBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
     assert_impl_all!(EmbedField: From<EmbedFieldBuilder>);
+    assert_impl_all!(EmbedFieldBuilder: From<EmbedField>);
 
     #[test]
     fn new_errors() {
@@ -108,6 +190,33 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn spacer() {
+        assert!(matches!(
+            EmbedBuilder::new().field(EmbedFieldBuilder::new(" ", "a")).build().unwrap_err().kind(),
+            EmbedErrorType::FieldNameBlank { name, .. }
+            if name == " "
+        ));
+        assert!(matches!(
+            EmbedBuilder::new()
+                .field(EmbedFieldBuilder::new("a", "\u{200b}\u{200b}"))
+                .build()
+                .unwrap_err()
+                .kind(),
+            EmbedErrorType::FieldValueBlank { .. }
+        ));
+
+        let embed = EmbedBuilder::new()
+            .field(EmbedFieldBuilder::spacer().inline())
+            .field(EmbedFieldBuilder::new(EmbedFieldBuilder::SPACER, "a"))
+            .build()
+            .unwrap();
+
+        assert_eq!(EmbedFieldBuilder::SPACER, embed.fields[0].name);
+        assert_eq!(EmbedFieldBuilder::SPACER, embed.fields[0].value);
+        assert!(embed.fields[0].inline);
+    }
+
     #[test]
     fn builder_inline() {
         let expected = EmbedField {
@@ -1,6 +1,9 @@
 //! Create embed footers.
 
-use super::image_source::ImageSource;
+use super::{
+    image_source::ImageSource,
+    sanitize::{PartKind, Sanitizer},
+};
 use twilight_model::channel::embed::EmbedFooter;
 
 /// Create an embed footer with a builder.
@@ -16,7 +19,7 @@ impl EmbedFooterBuilder {
     /// Create a new default embed footer builder.
     ///
     /// Refer to [`EmbedBuilder::FOOTER_TEXT_LENGTH_LIMIT`] for the maximum
-    /// number of UTF-16 code points that can be in a footer's text.
+    /// length of a footer's text.
     ///
     /// [`EmbedBuilder::FOOTER_TEXT_LENGTH_LIMIT`]: crate::EmbedBuilder::FOOTER_TEXT_LENGTH_LIMIT
     pub fn new(text: impl Into<String>) -> Self {
@@ -59,6 +62,55 @@ impl EmbedFooterBuilder {
 
         self
     }
+
+    /// Sanitize the footer's text.
+    ///
+    /// Refer to [`Sanitizer`] for how text is sanitized.
+    ///
+    /// [`Sanitizer`]: crate::Sanitizer
+    pub fn sanitize(mut self, sanitizer: &Sanitizer) -> Self {
+        sanitizer.sanitize(PartKind::FooterText, &mut self.0.text);
+
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EmbedFooterBuilder {
+    /// Deserialize an embed footer builder, rejecting icon URLs that aren't
+    /// valid image sources.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut footer = <EmbedFooter as serde::Deserialize>::deserialize(deserializer)?;
+
+        if let Some(icon_url) = footer.icon_url.take() {
+            footer.icon_url = Some(
+                ImageSource::parse(icon_url)
+                    .map_err(serde::de::Error::custom)?
+                    .0,
+            );
+        }
+
+        Ok(Self::from(footer))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for EmbedFooterBuilder {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+impl From<EmbedFooter> for EmbedFooterBuilder {
+    /// Create an embed footer builder from an existing embed footer.
+    ///
+    /// The proxied icon URL populated by Discord is removed.
+    fn from(footer: EmbedFooter) -> Self {
+        Self(EmbedFooter {
+            proxy_icon_url: None,
+            ..footer
+        })
+    }
 }
 
 impl From<EmbedFooterBuilder> for EmbedFooter {
@@ -80,6 +132,7 @@ mod tests {
 
     assert_impl_all!(EmbedFooterBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
     assert_impl_all!(EmbedFooter: From<EmbedFooterBuilder>);
+    assert_impl_all!(EmbedFooterBuilder: From<EmbedFooter>);
 
     #[test]
     fn text() {
@@ -115,4 +168,20 @@ mod tests {
         let actual = EmbedFooterBuilder::new("a footer").icon_url(image).build();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn from_footer() {
+        let footer = EmbedFooter {
+            icon_url: Some("https://example.com/1.png".to_owned()),
+            proxy_icon_url: Some("https://images-ext-1.discordapp.net/1.png".to_owned()),
+            text: "a footer".to_owned(),
+        };
+
+        let expected = EmbedFooter {
+            proxy_icon_url: None,
+            ..footer.clone()
+        };
+
+        assert_eq!(expected, EmbedFooterBuilder::from(footer).build());
+    }
 }
@@ -188,6 +188,35 @@ impl ImageSource {
 
         Ok(Self(url))
     }
+
+    /// Parse an image source from its URL, using the attachment rules for
+    /// URLs that start with `attachment://` and the URL rules otherwise.
+    #[cfg(feature = "serde")]
+    pub(crate) fn parse(source: String) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        match source.strip_prefix("attachment://") {
+            Some(filename) => Ok(Self::_attachment(filename)?),
+            None => Ok(Self::_url(source)?),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ImageSource {
+    /// Deserialize an image source from its URL.
+    ///
+    /// URLs starting with `attachment://` must be valid for
+    /// [`ImageSource::attachment`] and all other URLs must be valid for
+    /// [`ImageSource::url`].
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::parse(String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ImageSource {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
 }
 
 #[cfg(test)]
@@ -242,4 +271,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> Result<(), Box<dyn Error>> {
+        let attachment = ImageSource::attachment("abc.png")?;
+        assert_eq!(
+            "\"attachment://abc.png\"",
+            serde_json::to_string(&attachment)?
+        );
+        assert_eq!(
+            attachment,
+            serde_json::from_str("\"attachment://abc.png\"")?
+        );
+        assert_eq!(
+            ImageSource::url("https://example.com")?,
+            serde_json::from_str("\"https://example.com\"")?
+        );
+
+        assert!(serde_json::from_str::<ImageSource>("\"attachment://abc\"").is_err());
+        assert!(serde_json::from_str::<ImageSource>("\"ftp://example.com\"").is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,145 @@
+//! Turn values into embeds.
+
+use crate::EmbedBuilder;
+
+/// Value that can be shown as an embed.
+///
+/// With the `derive` feature, this can be derived for structs by annotating
+/// their fields with the part of the embed that they become. The derive macro
+/// documents the annotations.
+///
+/// Unlike conversions such as [`Into`], creating an embed borrows the value
+/// rather than consuming it. Embeds only need copies of the value's text and
+/// images, and the same value is often shown again after it changes, such as
+/// when editing a message.
+///
+/// # Examples
+///
+/// Implement the trait for a user profile:
+///
+/// ```
+/// use twilight_embed_builder::{EmbedBuilder, EmbedFieldBuilder, IntoEmbed};
+///
+/// struct Profile {
+///     name: String,
+///     level: u32,
+/// }
+///
+/// impl IntoEmbed for Profile {
+///     fn to_embed(&self) -> EmbedBuilder {
+///         EmbedBuilder::new()
+///             .title(&self.name)
+///             .field(EmbedFieldBuilder::new("Level", self.level.to_string()))
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let profile = Profile {
+///     name: "Twilight".to_owned(),
+///     level: 12,
+/// };
+/// let embed = profile.to_embed().build()?;
+///
+/// assert_eq!("12", embed.fields[0].value);
+/// # Ok(()) }
+/// ```
+pub trait IntoEmbed {
+    /// Create a builder for an embed showing the value.
+    ///
+    /// The builder can be changed further before it's built. The value is
+    /// borrowed, so it can still be used afterwards.
+    fn to_embed(&self) -> EmbedBuilder;
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use crate::{Color, EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder, IntoEmbed};
+
+    #[derive(IntoEmbed)]
+    #[embed(crate = "crate", color = 0x00_ff_00)]
+    struct Match {
+        #[embed(title(format = "{} match"))]
+        teams: &'static str,
+        #[embed(field(order = 1))]
+        winner: Option<&'static str>,
+        #[embed(field(inline, name = "Final score"))]
+        score: String,
+        #[embed(field(inline))]
+        goal_difference: u32,
+        #[embed(footer)]
+        league: Option<&'static str>,
+        #[allow(dead_code)]
+        id: u64,
+    }
+
+    #[derive(IntoEmbed)]
+    #[embed(crate = "crate")]
+    struct Labeled<T: std::fmt::Display, const N: usize> {
+        #[embed(title)]
+        label: T,
+        #[embed(field)]
+        r#type: &'static str,
+        #[embed(field(name = "Counts", format = "{:?}"))]
+        counts: [u8; N],
+    }
+
+    #[derive(IntoEmbed)]
+    #[embed(crate = "crate")]
+    struct Borrowed<'a> {
+        #[embed(description(format = "> {}"))]
+        quote: &'a str,
+    }
+
+    #[test]
+    fn derive() {
+        let mut value = Match {
+            teams: "Ponyville",
+            winner: Some("Ponyville"),
+            score: "3 - 2".to_owned(),
+            goal_difference: 1,
+            league: None,
+            id: 1,
+        };
+
+        assert_eq!(
+            EmbedBuilder::new()
+                .color(Color::new(0x00_ff_00).unwrap())
+                .title("Ponyville match")
+                .field(EmbedFieldBuilder::new("Final score", "3 - 2").inline())
+                .field(EmbedFieldBuilder::new("Goal difference", "1").inline())
+                .field(EmbedFieldBuilder::new("Winner", "Ponyville")),
+            value.to_embed()
+        );
+
+        value.winner = None;
+        value.league = Some("Equestria");
+        let embed = value.to_embed().build().unwrap();
+
+        assert_eq!(2, embed.fields.len());
+        assert_eq!(
+            Some(EmbedFooterBuilder::new("Equestria").build()),
+            embed.footer
+        );
+    }
+
+    #[test]
+    fn derive_generic() {
+        let value = Labeled {
+            label: 5,
+            r#type: "Generic",
+            counts: [1, 2],
+        };
+        let embed = value.to_embed().build().unwrap();
+
+        assert_eq!(Some("5"), embed.title.as_deref());
+        assert_eq!("Type", embed.fields[0].name);
+        assert_eq!("Generic", embed.fields[0].value);
+        assert_eq!("Counts", embed.fields[1].name);
+        assert_eq!("[1, 2]", embed.fields[1].value);
+
+        let text = String::from("Lifetimes work too.");
+        let embed = Borrowed { quote: &text }.to_embed().build().unwrap();
+
+        assert_eq!(Some("> Lifetimes work too."), embed.description.as_deref());
+    }
+}
@@ -0,0 +1,117 @@
+//! Count the length of embed text.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// How the length of text is counted against the limits of an embed.
+///
+/// Discord doesn't document how it counts lengths, and text such as emoji
+/// sequences is counted differently depending on the unit. The counting is
+/// set with [`EmbedBuilder::length_counting`], and is used by everything that
+/// measures the builder's text: validation in [`build`], the
+/// `*_LENGTH_LIMIT` constants, the remaining length methods, truncation and
+/// splitting.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::LengthCounting;
+///
+/// // A family emoji: four people joined by zero width joiners.
+/// let family = "👨\u{200d}👩\u{200d}👧\u{200d}👦";
+///
+/// assert_eq!(7, LengthCounting::Chars.count(family));
+/// assert_eq!(1, LengthCounting::Graphemes.count(family));
+/// assert_eq!(11, LengthCounting::Utf16.count(family));
+/// ```
+///
+/// [`EmbedBuilder::length_counting`]: crate::EmbedBuilder::length_counting
+/// [`build`]: crate::EmbedBuilder::build
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum LengthCounting {
+    /// Count Unicode scalar values, which are Rust's `char`s.
+    ///
+    /// This is the default.
+    Chars,
+    /// Count extended grapheme clusters, which are what's perceived as single
+    /// characters.
+    Graphemes,
+    /// Count UTF-16 code units, so characters outside of the Basic
+    /// Multilingual Plane, such as most emoji, count as two.
+    Utf16,
+}
+
+impl LengthCounting {
+    /// Length of text.
+    #[must_use = "counting the length has no effect if left unused"]
+    pub fn count(self, text: &str) -> usize {
+        match self {
+            Self::Chars => text.chars().count(),
+            Self::Graphemes => text.graphemes(true).count(),
+            Self::Utf16 => text.encode_utf16().count(),
+        }
+    }
+}
+
+impl Default for LengthCounting {
+    fn default() -> Self {
+        Self::Chars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LengthCounting;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        LengthCounting: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    fn counts(text: &str) -> [usize; 3] {
+        [
+            LengthCounting::Chars.count(text),
+            LengthCounting::Graphemes.count(text),
+            LengthCounting::Utf16.count(text),
+        ]
+    }
+
+    #[test]
+    fn emoji() {
+        assert_eq!([5, 5, 5], counts("hello"));
+        assert_eq!([1, 1, 2], counts("🦄"));
+        // Thumbs up with a skin tone modifier.
+        assert_eq!([2, 1, 4], counts("👍🏽"));
+        // Flag of Ukraine, made of two regional indicators.
+        assert_eq!([2, 1, 4], counts("🇺🇦"));
+    }
+
+    #[test]
+    fn zwj_sequences() {
+        // Rainbow flag: white flag, variation selector, joiner and rainbow.
+        assert_eq!([4, 1, 6], counts("🏳\u{fe0f}\u{200d}🌈"));
+        assert_eq!(
+            [12, 4, 18],
+            counts("👨\u{200d}👩\u{200d}👧\u{200d}👦 👩\u{200d}💻 ")
+        );
+    }
+
+    #[test]
+    fn combining_marks() {
+        // "é" as "e" followed by a combining acute accent.
+        assert_eq!([2, 1, 2], counts("e\u{301}"));
+        assert_eq!([1, 1, 1], counts("é"));
+        // Zalgo-style stacked marks stay in one grapheme.
+        assert_eq!([4, 1, 4], counts("a\u{300}\u{301}\u{302}"));
+        assert_eq!([5, 3, 5], counts("ne\u{301}e\u{301}"));
+    }
+}
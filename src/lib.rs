@@ -38,6 +38,43 @@
 //! # Ok(()) }
 //! ```
 //!
+//! ## Features
+//!
+//! ### `derive`
+//!
+//! The `derive` feature enables deriving [`IntoEmbed`] for structs, creating
+//! embeds from fields annotated with the part of the embed that they become.
+//!
+//! [`IntoEmbed`]: trait@IntoEmbed
+//!
+//! ### `serde`
+//!
+//! The `serde` feature implements `Deserialize` and `Serialize` for the
+//! builders and [`ImageSource`], in the shape of Discord's JSON embeds.
+//! Deserializing rejects image sources that couldn't be created with
+//! [`ImageSource::url`] or [`ImageSource::attachment`].
+//!
+//! ### `snapshot`
+//!
+//! The `snapshot` feature enables rendering embeds into SVG images that
+//! approximate the dark and light themes of the Discord client, laid out with
+//! bundled fonts so that no browser is needed.
+//!
+//! ### `snapshot-png`
+//!
+//! The `snapshot-png` feature additionally rasterizes those images into PNGs.
+//! This enables the `snapshot` feature.
+//!
+//! ### `template`
+//!
+//! The `template` feature enables the `template` module, which creates
+//! embeds from templates with placeholders loaded from JSON. This enables the
+//! `serde` feature.
+//!
+//! ### `template-toml`
+//!
+//! The `template-toml` feature additionally loads templates from TOML.
+//!
 //! [`twilight-rs`]: https://github.com/twilight-rs/twilight
 //! [`twilight-util`]: https://crates.io/crates/twilight-util
 //! [codecov badge]: https://img.shields.io/codecov/c/gh/twilight-rs/twilight?logo=codecov&style=for-the-badge&token=E9ERLJL0L2
@@ -64,17 +101,54 @@
     unsafe_code,
     unused
 )]
+pub mod color;
+pub mod diff;
 pub mod image_source;
+#[doc(hidden)]
+pub mod macros;
+pub mod markdown;
+pub mod message;
+pub mod patch;
+pub mod render;
+#[cfg(feature = "template")]
+pub mod template;
+pub mod typed;
 
+mod as_embed;
 mod author;
 mod builder;
 mod field;
 mod footer;
+mod into_embed;
+mod length;
+mod paginator;
+mod part;
+mod sanitize;
+mod split;
+mod truncate;
 
 pub use self::{
+    as_embed::AsEmbed,
     author::EmbedAuthorBuilder,
-    builder::{EmbedBuilder, EmbedError, EmbedErrorType},
+    builder::{EmbedBuilder, EmbedError, EmbedErrorType, EmbedValidationError},
+    color::Color,
+    diff::EmbedDiff,
     field::EmbedFieldBuilder,
     footer::EmbedFooterBuilder,
     image_source::ImageSource,
+    into_embed::IntoEmbed,
+    length::LengthCounting,
+    message::MessageEmbedsBuilder,
+    paginator::Paginator,
+    part::EmbedPart,
+    patch::EmbedPatch,
+    sanitize::{SanitizePolicy, Sanitizer},
+    truncate::Truncation,
+    typed::TypedEmbedBuilder,
 };
+
+#[cfg(feature = "derive")]
+pub use twilight_embed_builder_derive::IntoEmbed;
+
+#[cfg(feature = "template")]
+pub use self::template::EmbedTemplate;
@@ -0,0 +1,408 @@
+//! Support for the [`embed!`] macro.
+//!
+//! [`embed!`]: crate::embed
+
+use crate::Color;
+
+/// Number of characters in a string, as counted when validating embeds.
+///
+/// Strings are UTF-8, so this counts the bytes that don't continue a
+/// character.
+#[must_use = "counting characters has no effect if left unused"]
+pub const fn char_count(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut count = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] & 0b1100_0000 != 0b1000_0000 {
+            count += 1;
+        }
+
+        index += 1;
+    }
+
+    count
+}
+
+/// Create a color, failing to compile if it's invalid when used in a
+/// constant.
+#[must_use = "creating a color has no effect if left unused"]
+pub const fn color(color: u32) -> Color {
+    match Color::new(color) {
+        Ok(color) => color,
+        Err(_) => panic!("the color is invalid"),
+    }
+}
+
+/// Create an [`EmbedBuilder`] from a nested literal.
+///
+/// The embed is written as comma-separated parts in any order, each of which
+/// calls the builder method of the same name:
+///
+/// - `author: { name: .., url: .., icon_url: .. }`, where `name` comes first
+///   and the other parts are optional;
+/// - `color: ..`, either a [`Color`] or an integer literal;
+/// - `description: ..`;
+/// - `fields: [name => value, inline name => value, ..]`, where names and
+///   values are any expression and fields marked `inline` are inline;
+/// - `footer: { text: .., icon_url: .. }`, where `text` comes first and
+///   `icon_url` is optional;
+/// - `image: ..` and `thumbnail: ..`, which are [`ImageSource`]s;
+/// - `timestamp: ..`;
+/// - `title: ..`;
+/// - `url: ..`.
+///
+/// String literals are checked against the `*_LENGTH_LIMIT` constants of
+/// [`EmbedBuilder`], and integer literal colors against [`Color::new`], so
+/// that literals that are too long or invalid fail to compile. Other
+/// expressions are validated when the embed is built, as usual.
+///
+/// [`EmbedBuilder`]: crate::EmbedBuilder
+/// [`ImageSource`]: crate::ImageSource
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{embed, ImageSource};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let players = 12;
+///
+/// let embed = embed! {
+///     author: {
+///         name: "Twilight",
+///         url: "https://github.com/twilight-rs/twilight",
+///     },
+///     color: 0xfd_69_b3,
+///     title: "Server status",
+///     fields: [
+///         inline "Players" => players.to_string(),
+///         inline "Uptime" => "3 days",
+///         "Message of the day" => "Be nice to each other.",
+///     ],
+///     thumbnail: ImageSource::url("https://example.com/status.png")?,
+///     footer: { text: "Updated every minute" },
+/// }
+/// .build()?;
+///
+/// assert_eq!(3, embed.fields.len());
+/// assert!(embed.fields[0].inline);
+/// # Ok(()) }
+/// ```
+///
+/// Literals that are too long fail to compile:
+///
+/// ```compile_fail,E0080
+/// use twilight_embed_builder::embed;
+///
+/// let builder = embed! {
+///     title: "This title is far longer than the two hundred and fifty six \
+///         characters that Discord allows a title to have, which means that it \
+///         would be rejected when building the embed. Since it's a literal, it's \
+///         rejected before the program even compiles instead, which is a lot \
+///         earlier.",
+/// };
+/// ```
+///
+/// So do field names that are too long and colors that are invalid:
+///
+/// ```compile_fail,E0080
+/// use twilight_embed_builder::embed;
+///
+/// let builder = embed! {
+///     fields: [
+///         "This field name is longer than the two hundred and fifty six \
+///             characters that Discord allows the name of a field to have. Field \
+///             names are shown in bold above their values, so they're meant to be \
+///             short, and a name this long would be rejected by Discord when the \
+///             embed is sent." => "value",
+///     ],
+/// };
+/// ```
+///
+/// ```compile_fail,E0080
+/// use twilight_embed_builder::embed;
+///
+/// let builder = embed! {
+///     color: 0x1_00_00_00,
+/// };
+/// ```
+#[macro_export]
+macro_rules! embed {
+    (@check $text:literal, $limit:ident) => {
+        const _: () = ::core::assert!(
+            $crate::macros::char_count($text) <= $crate::EmbedBuilder::$limit,
+            ::core::concat!("text is longer than ", ::core::stringify!($limit)),
+        );
+    };
+    (@check $text:expr, $limit:ident) => {};
+
+    (@author $author:expr;) => { $author };
+    (@author $author:expr; icon_url: $icon_url:expr $(, $($rest:tt)*)?) => {
+        $crate::embed!(@author $author.icon_url($icon_url); $($($rest)*)?)
+    };
+    (@author $author:expr; url: $url:expr $(, $($rest:tt)*)?) => {
+        $crate::embed!(@author $author.url($url); $($($rest)*)?)
+    };
+
+    (@field $builder:expr; $name:tt => $value:tt $(, $inline:ident)?; $($rest:tt)*) => {
+        $crate::embed!(
+            @fields {
+                $crate::embed!(@check $name, FIELD_NAME_LENGTH_LIMIT);
+                $crate::embed!(@check $value, FIELD_VALUE_LENGTH_LIMIT);
+
+                $builder.field($crate::EmbedFieldBuilder::new($name, $value)$(.$inline())?)
+            };
+            $($rest)*
+        )
+    };
+
+    (@field_value $builder:expr; $name:tt $(, $inline:ident)?; $value:literal $(, $($rest:tt)*)?) => {
+        $crate::embed!(@field $builder; $name => $value $(, $inline)?; $($($rest)*)?)
+    };
+    (@field_value $builder:expr; $name:tt $(, $inline:ident)?; $value:expr $(, $($rest:tt)*)?) => {
+        $crate::embed!(@field $builder; $name => ($value) $(, $inline)?; $($($rest)*)?)
+    };
+
+    (@fields $builder:expr;) => { $builder };
+    (@fields $builder:expr; inline $name:literal => $($rest:tt)*) => {
+        $crate::embed!(@field_value $builder; $name, inline; $($rest)*)
+    };
+    (@fields $builder:expr; inline $name:expr => $($rest:tt)*) => {
+        $crate::embed!(@field_value $builder; ($name), inline; $($rest)*)
+    };
+    (@fields $builder:expr; $name:literal => $($rest:tt)*) => {
+        $crate::embed!(@field_value $builder; $name; $($rest)*)
+    };
+    (@fields $builder:expr; $name:expr => $($rest:tt)*) => {
+        $crate::embed!(@field_value $builder; ($name); $($rest)*)
+    };
+
+    (@footer $footer:expr;) => { $footer };
+    (@footer $footer:expr; icon_url: $icon_url:expr $(, $($rest:tt)*)?) => {
+        $crate::embed!(@footer $footer.icon_url($icon_url); $($($rest)*)?)
+    };
+
+    (@embed $builder:expr;) => { $builder };
+    (@embed $builder:expr; author: { name: $name:literal $(, $($author:tt)*)? } $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed_author $builder; $name; [$($($author)*)?] $($($rest)*)?)
+    };
+    (@embed $builder:expr; author: { name: $name:expr $(, $($author:tt)*)? } $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed_author $builder; ($name); [$($($author)*)?] $($($rest)*)?)
+    };
+    (@embed_author $builder:expr; $name:tt; [$($author:tt)*] $($rest:tt)*) => {
+        $crate::embed!(
+            @embed {
+                $crate::embed!(@check $name, AUTHOR_NAME_LENGTH_LIMIT);
+
+                $builder.author($crate::embed!(
+                    @author $crate::EmbedAuthorBuilder::new(::std::string::String::from($name));
+                    $($author)*
+                ))
+            };
+            $($rest)*
+        )
+    };
+    (@embed $builder:expr; color: $color:literal $(, $($rest:tt)*)?) => {
+        $crate::embed!(
+            @embed {
+                const COLOR: $crate::Color = $crate::macros::color($color);
+
+                $builder.color(COLOR)
+            };
+            $($($rest)*)?
+        )
+    };
+    (@embed $builder:expr; color: $color:expr $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed $builder.color($color); $($($rest)*)?)
+    };
+    (@embed $builder:expr; description: $description:literal $(, $($rest:tt)*)?) => {
+        $crate::embed!(
+            @embed {
+                $crate::embed!(@check $description, DESCRIPTION_LENGTH_LIMIT);
+
+                $builder.description($description)
+            };
+            $($($rest)*)?
+        )
+    };
+    (@embed $builder:expr; description: $description:expr $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed $builder.description($description); $($($rest)*)?)
+    };
+    (@embed $builder:expr; fields: [$($fields:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed $crate::embed!(@fields $builder; $($fields)*); $($($rest)*)?)
+    };
+    (@embed $builder:expr; footer: { text: $text:literal $(, $($footer:tt)*)? } $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed_footer $builder; $text; [$($($footer)*)?] $($($rest)*)?)
+    };
+    (@embed $builder:expr; footer: { text: $text:expr $(, $($footer:tt)*)? } $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed_footer $builder; ($text); [$($($footer)*)?] $($($rest)*)?)
+    };
+    (@embed_footer $builder:expr; $text:tt; [$($footer:tt)*] $($rest:tt)*) => {
+        $crate::embed!(
+            @embed {
+                $crate::embed!(@check $text, FOOTER_TEXT_LENGTH_LIMIT);
+
+                $builder.footer($crate::embed!(
+                    @footer $crate::EmbedFooterBuilder::new($text);
+                    $($footer)*
+                ))
+            };
+            $($rest)*
+        )
+    };
+    (@embed $builder:expr; image: $image:expr $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed $builder.image($image); $($($rest)*)?)
+    };
+    (@embed $builder:expr; thumbnail: $thumbnail:expr $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed $builder.thumbnail($thumbnail); $($($rest)*)?)
+    };
+    (@embed $builder:expr; timestamp: $timestamp:expr $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed $builder.timestamp($timestamp); $($($rest)*)?)
+    };
+    (@embed $builder:expr; title: $title:literal $(, $($rest:tt)*)?) => {
+        $crate::embed!(
+            @embed {
+                $crate::embed!(@check $title, TITLE_LENGTH_LIMIT);
+
+                $builder.title($title)
+            };
+            $($($rest)*)?
+        )
+    };
+    (@embed $builder:expr; title: $title:expr $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed $builder.title($title); $($($rest)*)?)
+    };
+    (@embed $builder:expr; url: $url:expr $(, $($rest:tt)*)?) => {
+        $crate::embed!(@embed $builder.url($url); $($($rest)*)?)
+    };
+
+    ($($embed:tt)*) => {
+        $crate::embed!(@embed $crate::EmbedBuilder::new(); $($embed)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::char_count;
+    use crate::{
+        Color, EmbedAuthorBuilder, EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder, ImageSource,
+    };
+    use static_assertions::const_assert_eq;
+    use std::error::Error;
+    use twilight_model::util::Timestamp;
+
+    const_assert_eq!(0, char_count(""));
+    const_assert_eq!(5, char_count("héllo"));
+    const_assert_eq!(2, char_count("👍🏽"));
+
+    #[test]
+    fn expands_to_builders() {
+        let value = String::from("value");
+        let builder = embed! {
+            title: "title",
+            color: Color::RED,
+            fields: [
+                "a" => value.clone(),
+                inline "b" => "c",
+            ],
+            footer: { text: "footer", },
+        };
+
+        assert_eq!(
+            EmbedBuilder::new()
+                .title("title")
+                .color(Color::RED)
+                .field(EmbedFieldBuilder::new("a", value))
+                .field(EmbedFieldBuilder::new("b", "c").inline())
+                .footer(EmbedFooterBuilder::new("footer")),
+            builder
+        );
+        assert_eq!(Some(0xfd_69_b3), embed! { color: 0xfd_69_b3 }.get_color());
+    }
+
+    #[test]
+    fn field_expressions() {
+        let name = String::from("name");
+        let builder = embed! {
+            fields: [
+                name.clone() => "literal",
+                inline name.to_uppercase() => name.len().to_string(),
+                "literal" => name.clone(),
+                inline "inline" => "literal",
+            ],
+        };
+
+        assert_eq!(
+            EmbedBuilder::new()
+                .field(EmbedFieldBuilder::new("name", "literal"))
+                .field(EmbedFieldBuilder::new("NAME", "4").inline())
+                .field(EmbedFieldBuilder::new("literal", "name"))
+                .field(EmbedFieldBuilder::new("inline", "literal").inline()),
+            builder
+        );
+    }
+
+    #[test]
+    fn expands_every_part() -> Result<(), Box<dyn Error>> {
+        let name = String::from("Twilight");
+        let color = Color::new(0x00_ff_00)?;
+        let icon = ImageSource::url("https://example.com/icon.png")?;
+        let image = ImageSource::url("https://example.com/image.png")?;
+        let thumbnail = ImageSource::attachment("thumbnail.png")?;
+        let timestamp = Timestamp::from_secs(1_627_923_403)?;
+        let builder = embed! {
+            author: {
+                name: name.clone(),
+                icon_url: icon.clone(),
+                url: "https://example.com",
+            },
+            color: color,
+            description: name.to_uppercase(),
+            image: image.clone(),
+            thumbnail: thumbnail.clone(),
+            timestamp: timestamp,
+            url: "https://example.com/status",
+            footer: {
+                text: "footer",
+                icon_url: icon.clone(),
+            },
+        };
+
+        assert_eq!(
+            EmbedBuilder::new()
+                .author(
+                    EmbedAuthorBuilder::new(name.clone())
+                        .icon_url(icon.clone())
+                        .url("https://example.com")
+                )
+                .color(color)
+                .description("TWILIGHT")
+                .image(image)
+                .thumbnail(thumbnail)
+                .timestamp(timestamp)
+                .url("https://example.com/status")
+                .footer(EmbedFooterBuilder::new("footer").icon_url(icon.clone())),
+            builder
+        );
+
+        let builder = embed! {
+            author: { name: "Twilight", url: "https://example.com", icon_url: icon.clone() },
+            footer: { text: name.clone() },
+        };
+
+        assert_eq!(
+            EmbedBuilder::new()
+                .author(
+                    EmbedAuthorBuilder::new(name.clone())
+                        .url("https://example.com")
+                        .icon_url(icon)
+                )
+                .footer(EmbedFooterBuilder::new(name)),
+            builder
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,683 @@
+//! Compose Discord markdown for the text of embeds.
+
+use crate::part::EmbedPart;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Zero width space, used to break up sequences that can't be escaped.
+const ZERO_WIDTH_SPACE: char = '\u{200b}';
+
+/// Markdown construct that text can be formatted with.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum MarkdownConstruct {
+    /// Lines prefixed with `> `.
+    BlockQuote,
+    /// Text wrapped in `**`.
+    Bold,
+    /// Text wrapped in ```` ``` ````.
+    CodeBlock,
+    /// Character escaped with a backslash, such as `\*`.
+    Escape,
+    /// Line prefixed with `# `, `## ` or `### `.
+    Header,
+    /// Text wrapped in `` ` ``.
+    InlineCode,
+    /// Text wrapped in `*`.
+    Italic,
+    /// Lines prefixed with `- ` or `1. `.
+    List,
+    /// Link in the form of `[text](url)`.
+    MaskedLink,
+    /// Text wrapped in `||`.
+    Spoiler,
+    /// Text wrapped in `~~`.
+    Strikethrough,
+    /// Text wrapped in `__`.
+    Underline,
+}
+
+impl MarkdownConstruct {
+    /// Every construct.
+    const ALL: [Self; 12] = [
+        Self::BlockQuote,
+        Self::Bold,
+        Self::CodeBlock,
+        Self::Escape,
+        Self::Header,
+        Self::InlineCode,
+        Self::Italic,
+        Self::List,
+        Self::MaskedLink,
+        Self::Spoiler,
+        Self::Strikethrough,
+        Self::Underline,
+    ];
+
+    /// Whether the construct is rendered by Discord in a part of an embed.
+    ///
+    /// Author names and footer text don't render any markdown, not even
+    /// escapes, so their backslashes are shown. Titles and field names only
+    /// render escapes and inline formatting, while descriptions and field
+    /// values render every construct.
+    #[must_use = "retrieving whether the construct renders has no effect if left unused"]
+    pub const fn renders_in(self, part: EmbedPart) -> bool {
+        match part {
+            EmbedPart::AuthorName | EmbedPart::FooterText => false,
+            EmbedPart::FieldName(_) | EmbedPart::Title => matches!(
+                self,
+                Self::Bold
+                    | Self::Escape
+                    | Self::InlineCode
+                    | Self::Italic
+                    | Self::Spoiler
+                    | Self::Strikethrough
+                    | Self::Underline
+            ),
+            EmbedPart::Description | EmbedPart::FieldValue(_) => true,
+        }
+    }
+
+    /// Bit of the construct in a set of constructs.
+    const fn bit(self) -> u16 {
+        1 << self as u16
+    }
+
+    /// Whether the construct has to start on its own line.
+    const fn is_block(self) -> bool {
+        matches!(
+            self,
+            Self::BlockQuote | Self::CodeBlock | Self::Header | Self::List
+        )
+    }
+}
+
+/// Discord markdown composed from escaped text and formatting constructs.
+///
+/// Plain text is always escaped, constructs can be nested within each other,
+/// and the constructs that were used are tracked so that it can be checked
+/// whether they render in a given part of an embed.
+///
+/// Markdown converts into a [`String`], so it can be passed directly to
+/// methods such as [`EmbedBuilder::description`] and
+/// [`EmbedFieldBuilder::new`].
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{markdown::Markdown, EmbedBuilder, EmbedPart};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let description = Markdown::text("Run ")
+///     .push(Markdown::inline_code("cargo build"))
+///     .push(" to build *everything*, then read ")
+///     .push(Markdown::masked_link(
+///         Markdown::bold("the docs"),
+///         "https://docs.rs/twilight-embed-builder",
+///     ));
+///
+/// assert_eq!(
+///     "Run `cargo build` to build \\*everything\\*, then read \
+///     [**the docs**](https://docs.rs/twilight-embed-builder)",
+///     description.as_str(),
+/// );
+/// assert!(description.renders_in(EmbedPart::Description));
+/// assert!(!description.renders_in(EmbedPart::Title));
+///
+/// let embed = EmbedBuilder::new().description(description).build()?;
+/// # Ok(()) }
+/// ```
+///
+/// [`EmbedBuilder::description`]: crate::EmbedBuilder::description
+/// [`EmbedFieldBuilder::new`]: crate::EmbedFieldBuilder::new
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[must_use = "markdown has no effect if left unused"]
+pub struct Markdown {
+    /// Whether the last construct is a block that following content must not
+    /// be on the same line as.
+    block_end: bool,
+    constructs: u16,
+    content: String,
+}
+
+impl Markdown {
+    /// Create empty markdown.
+    pub const fn new() -> Self {
+        Self {
+            block_end: false,
+            constructs: 0,
+            content: String::new(),
+        }
+    }
+
+    /// Create markdown from plain text, escaping it.
+    ///
+    /// Refer to [`escape`] for what is escaped.
+    pub fn text(text: impl AsRef<str>) -> Self {
+        let text = text.as_ref();
+        let content = escape(text);
+
+        Self {
+            block_end: false,
+            constructs: if content.len() == text.len() {
+                0
+            } else {
+                MarkdownConstruct::Escape.bit()
+            },
+            content,
+        }
+    }
+
+    /// Create markdown from text that is already formatted, without escaping
+    /// it.
+    ///
+    /// The constructs within the text aren't tracked, so they aren't taken
+    /// into account by [`renders_in`].
+    ///
+    /// [`renders_in`]: Self::renders_in
+    pub fn raw(markdown: impl Into<String>) -> Self {
+        Self {
+            block_end: false,
+            constructs: 0,
+            content: markdown.into(),
+        }
+    }
+
+    /// Block quote, with each line prefixed with `> `.
+    pub fn block_quote(inner: impl Into<Self>) -> Self {
+        let inner = inner.into();
+        let content = prefix_lines(&inner.content, "> ", "> ");
+
+        Self::block(MarkdownConstruct::BlockQuote, inner.constructs, content)
+    }
+
+    /// Bold text.
+    pub fn bold(inner: impl Into<Self>) -> Self {
+        Self::wrap(MarkdownConstruct::Bold, "**", inner.into())
+    }
+
+    /// Code block, optionally with a language to highlight the code as.
+    ///
+    /// The code is not escaped, since escapes aren't rendered within code
+    /// blocks. Any closing fence within the code is broken up with a zero
+    /// width space instead.
+    pub fn code_block(language: Option<&str>, code: impl AsRef<str>) -> Self {
+        let language = language
+            .unwrap_or_default()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '#' | '.'))
+            .collect::<String>();
+        let code = code
+            .as_ref()
+            .replace("```", &format!("`{ZERO_WIDTH_SPACE}``"));
+        let newline = if code.ends_with('\n') { "" } else { "\n" };
+
+        Self::block(
+            MarkdownConstruct::CodeBlock,
+            0,
+            format!("```{language}\n{code}{newline}```"),
+        )
+    }
+
+    /// Header with a level from 1 to 3, with 1 being the largest.
+    ///
+    /// Levels outside of that range are clamped.
+    pub fn header(level: u8, inner: impl Into<Self>) -> Self {
+        let inner = inner.into();
+        let hashes = "#".repeat(usize::from(level.clamp(1, 3)));
+        let text = inner.content.replace('\n', " ");
+
+        Self::block(
+            MarkdownConstruct::Header,
+            inner.constructs,
+            format!("{hashes} {text}"),
+        )
+    }
+
+    /// Inline code.
+    ///
+    /// The code is not escaped, since escapes aren't rendered within code.
+    /// Backticks within the code are handled by wrapping it in double
+    /// backticks.
+    pub fn inline_code(code: impl AsRef<str>) -> Self {
+        let code = code
+            .as_ref()
+            .replace('\n', " ")
+            .replace("``", &format!("`{ZERO_WIDTH_SPACE}`"));
+
+        let content = if code.is_empty() {
+            String::new()
+        } else if code.contains('`') {
+            let padding = if code.starts_with('`') || code.ends_with('`') {
+                " "
+            } else {
+                ""
+            };
+
+            format!("``{padding}{code}{padding}``")
+        } else {
+            format!("`{code}`")
+        };
+
+        Self {
+            block_end: false,
+            constructs: if content.is_empty() {
+                0
+            } else {
+                MarkdownConstruct::InlineCode.bit()
+            },
+            content,
+        }
+    }
+
+    /// Italic text.
+    pub fn italic(inner: impl Into<Self>) -> Self {
+        Self::wrap(MarkdownConstruct::Italic, "*", inner.into())
+    }
+
+    /// Unordered list, with each item prefixed with `- `.
+    pub fn list(items: impl IntoIterator<Item = impl Into<Self>>) -> Self {
+        Self::list_with(items, |_| "-".to_owned())
+    }
+
+    /// Masked link, which displays text that links to a URL.
+    ///
+    /// Parentheses and spaces in the URL are percent-encoded so that they
+    /// don't end the link early.
+    pub fn masked_link(text: impl Into<Self>, url: impl AsRef<str>) -> Self {
+        let text = text.into();
+        let url = url
+            .as_ref()
+            .replace('(', "%28")
+            .replace(')', "%29")
+            .replace(' ', "%20");
+
+        Self {
+            block_end: false,
+            constructs: text.constructs | MarkdownConstruct::MaskedLink.bit(),
+            content: format!("[{}]({url})", text.content.replace('\n', " ")),
+        }
+    }
+
+    /// Ordered list, with each item prefixed with its number.
+    pub fn ordered_list(items: impl IntoIterator<Item = impl Into<Self>>) -> Self {
+        Self::list_with(items, |idx| format!("{}.", idx + 1))
+    }
+
+    /// Spoiler, which hides text until it's clicked.
+    pub fn spoiler(inner: impl Into<Self>) -> Self {
+        Self::wrap(MarkdownConstruct::Spoiler, "||", inner.into())
+    }
+
+    /// Struck through text.
+    pub fn strikethrough(inner: impl Into<Self>) -> Self {
+        Self::wrap(MarkdownConstruct::Strikethrough, "~~", inner.into())
+    }
+
+    /// Underlined text.
+    pub fn underline(inner: impl Into<Self>) -> Self {
+        Self::wrap(MarkdownConstruct::Underline, "__", inner.into())
+    }
+
+    /// Append markdown, or plain text which is escaped.
+    ///
+    /// Blocks such as block quotes, code blocks, headers and lists are
+    /// separated from surrounding content by a line break.
+    pub fn push(mut self, other: impl Into<Self>) -> Self {
+        let other = other.into();
+
+        if other.content.is_empty() {
+            return self;
+        }
+
+        let other_is_block = MarkdownConstruct::ALL.iter().any(|construct| {
+            construct.is_block()
+                && other.constructs & construct.bit() != 0
+                && other.content.starts_with(block_prefix(*construct))
+        });
+
+        if (self.block_end || other_is_block)
+            && !self.content.is_empty()
+            && !self.content.ends_with('\n')
+        {
+            self.content.push('\n');
+        }
+
+        self.block_end = other.block_end;
+        self.constructs |= other.constructs;
+        self.content.push_str(&other.content);
+
+        self
+    }
+
+    /// Formatted markdown.
+    #[must_use = "retrieving the markdown has no effect if left unused"]
+    pub fn as_str(&self) -> &str {
+        &self.content
+    }
+
+    /// Constructs used in the markdown, excluding those in [raw] markdown.
+    ///
+    /// [raw]: Self::raw
+    #[must_use = "retrieving the constructs has no effect if left unused"]
+    pub fn constructs(&self) -> Vec<MarkdownConstruct> {
+        MarkdownConstruct::ALL
+            .iter()
+            .copied()
+            .filter(|construct| self.constructs & construct.bit() != 0)
+            .collect()
+    }
+
+    /// Consume the markdown, returning the formatted string.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "consuming the markdown has no effect if left unused"]
+    pub fn into_string(self) -> String {
+        self.content
+    }
+
+    /// Whether every construct used in the markdown is rendered by Discord in
+    /// a part of an embed.
+    ///
+    /// Refer to [`MarkdownConstruct::renders_in`] for which constructs render
+    /// in which parts.
+    #[must_use = "retrieving whether the markdown renders has no effect if left unused"]
+    pub fn renders_in(&self, part: EmbedPart) -> bool {
+        self.constructs()
+            .into_iter()
+            .all(|construct| construct.renders_in(part))
+    }
+
+    const fn block(construct: MarkdownConstruct, constructs: u16, content: String) -> Self {
+        Self {
+            block_end: true,
+            constructs: constructs | construct.bit(),
+            content,
+        }
+    }
+
+    fn list_with(
+        items: impl IntoIterator<Item = impl Into<Self>>,
+        marker: impl Fn(usize) -> String,
+    ) -> Self {
+        let mut constructs = 0;
+        let mut lines = Vec::new();
+
+        for (idx, item) in items.into_iter().enumerate() {
+            let item = item.into();
+            let marker = marker(idx);
+            let indent = " ".repeat(marker.len() + 1);
+
+            constructs |= item.constructs;
+            lines.push(prefix_lines(&item.content, &format!("{marker} "), &indent));
+        }
+
+        if lines.is_empty() {
+            return Self::new();
+        }
+
+        Self::block(MarkdownConstruct::List, constructs, lines.join("\n"))
+    }
+
+    /// Wrap markdown in a delimiter, moving surrounding whitespace outside of
+    /// the delimiters since Discord doesn't render emphasis that starts or
+    /// ends with whitespace.
+    fn wrap(construct: MarkdownConstruct, delimiter: &str, inner: Self) -> Self {
+        let trimmed = inner.content.trim();
+
+        if trimmed.is_empty() {
+            return inner;
+        }
+
+        let start = inner.content.len() - inner.content.trim_start().len();
+        let end = start + trimmed.len();
+
+        Self {
+            block_end: inner.block_end,
+            constructs: inner.constructs | construct.bit(),
+            content: format!(
+                "{}{delimiter}{trimmed}{delimiter}{}",
+                &inner.content[..start],
+                &inner.content[end..],
+            ),
+        }
+    }
+}
+
+impl Display for Markdown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.content)
+    }
+}
+
+impl From<&str> for Markdown {
+    /// Create markdown from plain text, escaping it.
+    ///
+    /// This is equivalent to [`Markdown::text`].
+    fn from(text: &str) -> Self {
+        Self::text(text)
+    }
+}
+
+impl From<String> for Markdown {
+    /// Create markdown from plain text, escaping it.
+    ///
+    /// This is equivalent to [`Markdown::text`].
+    fn from(text: String) -> Self {
+        Self::text(text)
+    }
+}
+
+impl From<Markdown> for String {
+    /// Convert markdown into its formatted string.
+    ///
+    /// This is equivalent to [`Markdown::into_string`].
+    fn from(markdown: Markdown) -> Self {
+        markdown.into_string()
+    }
+}
+
+/// Escape text so that Discord renders it literally instead of as markdown.
+///
+/// Characters that format text, such as `*`, `_`, `` ` ``, `~` and `|`, are
+/// escaped with a backslash, as are brackets that could form a masked link
+/// and angle brackets that could form a mention. Headers, block quotes and
+/// lists are escaped when they start a line.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::markdown;
+///
+/// assert_eq!(r"\*\*not bold\*\*", markdown::escape("**not bold**"));
+/// assert_eq!(r"\> not a quote", markdown::escape("> not a quote"));
+/// ```
+#[must_use = "escaping text has no effect if left unused"]
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for (idx, line) in text.split('\n').enumerate() {
+        if idx > 0 {
+            escaped.push('\n');
+        }
+
+        let indent = line.len() - line.trim_start().len();
+        let (whitespace, line) = line.split_at(indent);
+        escaped.push_str(whitespace);
+
+        let marker = block_marker(line);
+
+        for (idx, c) in line.char_indices() {
+            if marker == Some(idx)
+                || matches!(
+                    c,
+                    '\\' | '*' | '_' | '`' | '~' | '|' | '[' | ']' | '<' | '>'
+                )
+            {
+                escaped.push('\\');
+            }
+
+            escaped.push(c);
+        }
+    }
+
+    escaped
+}
+
+/// Prefix that a block construct starts with.
+const fn block_prefix(construct: MarkdownConstruct) -> &'static str {
+    match construct {
+        MarkdownConstruct::BlockQuote => ">",
+        MarkdownConstruct::CodeBlock => "```",
+        MarkdownConstruct::Header => "#",
+        _ => "",
+    }
+}
+
+/// Prefix the first line of text with one string and the remaining lines
+/// with another.
+fn prefix_lines(text: &str, first: &str, rest: &str) -> String {
+    text.split('\n')
+        .enumerate()
+        .map(|(idx, line)| format!("{}{line}", if idx == 0 { first } else { rest }))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Byte index of the punctuation that makes a line, excluding leading
+/// whitespace, start a header or a list that isn't otherwise escaped.
+///
+/// Discord only treats a backslash before punctuation as an escape, so an
+/// ordered list is escaped by the period after its number.
+fn block_marker(line: &str) -> Option<usize> {
+    if line.starts_with("# ") || line.starts_with("## ") || line.starts_with("### ") {
+        return Some(0);
+    }
+
+    if line.starts_with("- ") || line.starts_with("+ ") {
+        return Some(0);
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+
+    (digits > 0 && line[digits..].starts_with(". ")).then(|| digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape, Markdown, MarkdownConstruct};
+    use crate::EmbedPart;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(MarkdownConstruct: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(
+        Markdown: Clone,
+        Debug,
+        Default,
+        Eq,
+        From<&'static str>,
+        From<String>,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_impl_all!(String: From<Markdown>);
+
+    #[test]
+    fn escaping() {
+        assert_eq!(r"a\_b \| c\\", escape(r"a_b | c\"));
+        assert_eq!(r"\[not\](a link) \<@123\>", escape("[not](a link) <@123>"));
+        assert_eq!(
+            "\\# one\n  \\- two\n1\\. three",
+            escape("# one\n  - two\n1. three")
+        );
+        assert_eq!("#hashtag 1.5", escape("#hashtag 1.5"));
+    }
+
+    #[test]
+    fn nesting() {
+        let markdown = Markdown::bold(Markdown::italic("both"))
+            .push(" and ")
+            .push(Markdown::underline(Markdown::strikethrough("a*b")));
+
+        assert_eq!("***both*** and __~~a\\*b~~__", markdown.as_str());
+        assert_eq!(
+            [
+                MarkdownConstruct::Bold,
+                MarkdownConstruct::Escape,
+                MarkdownConstruct::Italic,
+                MarkdownConstruct::Strikethrough,
+                MarkdownConstruct::Underline,
+            ],
+            markdown.constructs().as_slice()
+        );
+    }
+
+    #[test]
+    fn whitespace() {
+        assert_eq!(
+            "a **b** c",
+            Markdown::text("a")
+                .push(Markdown::bold(" b "))
+                .push("c")
+                .as_str()
+        );
+        assert_eq!("  ", Markdown::spoiler("  ").as_str());
+        assert!(Markdown::spoiler("").constructs().is_empty());
+    }
+
+    #[test]
+    fn code() {
+        assert_eq!("`a*b`", Markdown::inline_code("a*b").as_str());
+        assert_eq!("``a`b``", Markdown::inline_code("a`b").as_str());
+        assert_eq!("`` `a ``", Markdown::inline_code("`a").as_str());
+        assert_eq!(
+            "```rs\nlet a = \"`\u{200b}``\";\n```",
+            Markdown::code_block(Some("rs"), "let a = \"```\";").as_str()
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        let markdown = Markdown::text("intro")
+            .push(Markdown::block_quote("quoted\nlines"))
+            .push(Markdown::list(["one", "two\nlines"]))
+            .push(Markdown::ordered_list([Markdown::bold("first")]))
+            .push(Markdown::header(2, "header"))
+            .push("outro");
+
+        assert_eq!(
+            "intro\n> quoted\n> lines\n- one\n- two\n  lines\n1. **first**\n## header\noutro",
+            markdown.as_str()
+        );
+        assert_eq!("", Markdown::list(Vec::<Markdown>::new()).as_str());
+    }
+
+    #[test]
+    fn links() {
+        assert_eq!(
+            "[a \\[b\\]](https://example.com/a%20%28b%29)",
+            Markdown::masked_link("a [b]", "https://example.com/a (b)").as_str()
+        );
+    }
+
+    #[test]
+    fn renders_in() {
+        let bold = Markdown::bold("a");
+        assert!(bold.renders_in(EmbedPart::Title));
+        assert!(bold.renders_in(EmbedPart::FieldName(0)));
+        assert!(!bold.renders_in(EmbedPart::FooterText));
+        assert!(!bold.renders_in(EmbedPart::AuthorName));
+
+        let list = Markdown::list(["a"]);
+        assert!(!list.renders_in(EmbedPart::Title));
+        assert!(list.renders_in(EmbedPart::FieldValue(0)));
+
+        let escaped = Markdown::text("*");
+        assert!(escaped.renders_in(EmbedPart::Title));
+        assert!(!escaped.renders_in(EmbedPart::FooterText));
+        assert!(!escaped.renders_in(EmbedPart::AuthorName));
+        assert!(Markdown::text("a").renders_in(EmbedPart::FooterText));
+    }
+}
@@ -0,0 +1,302 @@
+//! Create the embeds of a message while enforcing message-wide limits.
+
+use crate::{part::EmbedPart, EmbedBuilder};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::channel::embed::Embed;
+
+/// Error building the embeds of a message.
+///
+/// This is returned from [`MessageEmbedsBuilder::build`].
+#[derive(Debug)]
+pub struct MessageEmbedsError {
+    kind: MessageEmbedsErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl MessageEmbedsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &MessageEmbedsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (MessageEmbedsErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for MessageEmbedsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            MessageEmbedsErrorType::EmbedInvalid { index } => {
+                f.write_str("embed ")?;
+                Display::fmt(index, f)?;
+
+                f.write_str(" is invalid")
+            }
+            MessageEmbedsErrorType::TooManyEmbeds { count } => {
+                Display::fmt(count, f)?;
+                f.write_str(" embeds were provided, but the limit is ")?;
+
+                Display::fmt(&MessageEmbedsBuilder::EMBED_COUNT_LIMIT, f)
+            }
+            MessageEmbedsErrorType::TotalContentTooLarge { index, length } => {
+                f.write_str("embed ")?;
+                Display::fmt(index, f)?;
+                f.write_str(" brings the total content of the message to ")?;
+                Display::fmt(length, f)?;
+
+                f.write_str(", which is too large")
+            }
+        }
+    }
+}
+
+impl Error for MessageEmbedsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`MessageEmbedsError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MessageEmbedsErrorType {
+    /// An embed failed to build.
+    ///
+    /// The source of the error is the [`EmbedError`] returned while building
+    /// it.
+    ///
+    /// [`EmbedError`]: crate::EmbedError
+    EmbedInvalid {
+        /// Index of the embed.
+        index: usize,
+    },
+    /// Too many embeds were provided.
+    ///
+    /// Refer to [`MessageEmbedsBuilder::EMBED_COUNT_LIMIT`] for the limit.
+    TooManyEmbeds {
+        /// Number of embeds provided.
+        count: usize,
+    },
+    /// The combined content of the embeds is too large.
+    ///
+    /// Refer to [`MessageEmbedsBuilder::EMBED_LENGTH_LIMIT`] for the limit.
+    TotalContentTooLarge {
+        /// Index of the first embed that brought the combined length over the
+        /// limit.
+        index: usize,
+        /// Combined length of the embeds up to and including the embed at
+        /// `index`.
+        length: usize,
+    },
+}
+
+/// Create the embeds of a message with a builder.
+///
+/// Discord applies [`EMBED_LENGTH_LIMIT`] to the combined content of every
+/// embed in a message rather than to each embed, so a message can be rejected
+/// even when each of its embeds is valid on its own.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{EmbedBuilder, MessageEmbedsBuilder};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let embeds = MessageEmbedsBuilder::new()
+///     .embed(EmbedBuilder::new().title("first"))
+///     .embed(EmbedBuilder::new().title("second"))
+///     .build()?;
+///
+/// assert_eq!(2, embeds.len());
+/// # Ok(()) }
+/// ```
+///
+/// [`EMBED_LENGTH_LIMIT`]: Self::EMBED_LENGTH_LIMIT
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+#[must_use = "must be built into embeds"]
+pub struct MessageEmbedsBuilder(Vec<EmbedBuilder>);
+
+impl MessageEmbedsBuilder {
+    /// The maximum number of embeds that can be in a message.
+    pub const EMBED_COUNT_LIMIT: usize = 10;
+
+    /// The maximum combined textual length of every embed in a message.
+    ///
+    /// Refer to [`EmbedBuilder::EMBED_LENGTH_LIMIT`] for what counts towards
+    /// the length of an embed.
+    pub const EMBED_LENGTH_LIMIT: usize = 6000;
+
+    /// Create a new builder without any embeds.
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Build the embeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MessageEmbedsErrorType::TooManyEmbeds`] error type if there
+    /// are more than [`EMBED_COUNT_LIMIT`] embeds.
+    ///
+    /// Returns a [`MessageEmbedsErrorType::EmbedInvalid`] error type if an
+    /// embed fails to build.
+    ///
+    /// Returns a [`MessageEmbedsErrorType::TotalContentTooLarge`] error type if
+    /// the combined content of the embeds is larger than
+    /// [`EMBED_LENGTH_LIMIT`].
+    ///
+    /// [`EMBED_COUNT_LIMIT`]: Self::EMBED_COUNT_LIMIT
+    /// [`EMBED_LENGTH_LIMIT`]: Self::EMBED_LENGTH_LIMIT
+    #[must_use = "should be used as part of a message"]
+    pub fn build(self) -> Result<Vec<Embed>, MessageEmbedsError> {
+        if self.0.len() > Self::EMBED_COUNT_LIMIT {
+            return Err(MessageEmbedsError {
+                kind: MessageEmbedsErrorType::TooManyEmbeds {
+                    count: self.0.len(),
+                },
+                source: None,
+            });
+        }
+
+        let mut embeds = Vec::with_capacity(self.0.len());
+        let mut total = 0;
+
+        for (index, builder) in self.0.into_iter().enumerate() {
+            let counting = builder.1;
+            let embed = builder.build().map_err(|source| MessageEmbedsError {
+                kind: MessageEmbedsErrorType::EmbedInvalid { index },
+                source: Some(Box::new(source)),
+            })?;
+
+            total += EmbedPart::total_length(&embed, counting);
+
+            if total > Self::EMBED_LENGTH_LIMIT {
+                return Err(MessageEmbedsError {
+                    kind: MessageEmbedsErrorType::TotalContentTooLarge {
+                        index,
+                        length: total,
+                    },
+                    source: None,
+                });
+            }
+
+            embeds.push(embed);
+        }
+
+        Ok(embeds)
+    }
+
+    /// Add an embed.
+    ///
+    /// Refer to [`EMBED_COUNT_LIMIT`] for the maximum number of embeds.
+    ///
+    /// [`EMBED_COUNT_LIMIT`]: Self::EMBED_COUNT_LIMIT
+    pub fn embed(mut self, embed: EmbedBuilder) -> Self {
+        self.0.push(embed);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MessageEmbedsBuilder, MessageEmbedsError, MessageEmbedsErrorType};
+    use crate::{EmbedBuilder, EmbedError, EmbedErrorType};
+    use static_assertions::{assert_fields, assert_impl_all, const_assert};
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(MessageEmbedsErrorType: Debug, Send, Sync);
+    assert_fields!(MessageEmbedsErrorType::EmbedInvalid: index);
+    assert_fields!(MessageEmbedsErrorType::TooManyEmbeds: count);
+    assert_fields!(MessageEmbedsErrorType::TotalContentTooLarge: index, length);
+    assert_impl_all!(MessageEmbedsError: Error, Send, Sync);
+    assert_impl_all!(
+        MessageEmbedsBuilder: Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    const_assert!(MessageEmbedsBuilder::EMBED_COUNT_LIMIT == 10);
+    const_assert!(MessageEmbedsBuilder::EMBED_LENGTH_LIMIT == 6000);
+
+    #[test]
+    fn too_many_embeds() {
+        let builder = (0..11).fold(MessageEmbedsBuilder::new(), |builder, _| {
+            builder.embed(EmbedBuilder::new().title("title"))
+        });
+
+        assert!(matches!(
+            builder.build().unwrap_err().kind(),
+            MessageEmbedsErrorType::TooManyEmbeds { count: 11 }
+        ));
+    }
+
+    #[test]
+    fn embed_invalid() {
+        let error = MessageEmbedsBuilder::new()
+            .embed(EmbedBuilder::new().title("title"))
+            .embed(EmbedBuilder::new().title(""))
+            .build()
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            MessageEmbedsErrorType::EmbedInvalid { index: 1 }
+        ));
+
+        let source = error
+            .into_source()
+            .unwrap()
+            .downcast::<EmbedError>()
+            .unwrap();
+        assert!(matches!(source.kind(), EmbedErrorType::TitleEmpty { .. }));
+    }
+
+    #[test]
+    fn total_content_too_large() {
+        let description = "a".repeat(EmbedBuilder::DESCRIPTION_LENGTH_LIMIT);
+
+        assert!(MessageEmbedsBuilder::new()
+            .embed(EmbedBuilder::new().description(&description[..3000]))
+            .embed(EmbedBuilder::new().description(&description[..3000]))
+            .build()
+            .is_ok());
+
+        assert!(matches!(
+            MessageEmbedsBuilder::new()
+                .embed(EmbedBuilder::new().title("title"))
+                .embed(EmbedBuilder::new().description(description.clone()))
+                .embed(EmbedBuilder::new().description(description))
+                .build()
+                .unwrap_err()
+                .kind(),
+            MessageEmbedsErrorType::TotalContentTooLarge {
+                index: 2,
+                length: 8197
+            }
+        ));
+    }
+}
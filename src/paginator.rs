@@ -0,0 +1,439 @@
+//! Split a list of entries into pages of embeds.
+
+use crate::{part::EmbedPart, truncate, EmbedBuilder};
+use std::ops::Range;
+use twilight_model::channel::embed::{EmbedField, EmbedFooter};
+
+/// Separator between the footer text of the template and the page label.
+const LABEL_SEPARATOR: &str = " • ";
+
+/// Entries that are packed into pages.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Entries {
+    /// Fields appended after the fields of the template.
+    Fields(Vec<EmbedField>),
+    /// Lines that make up the description of each page.
+    Lines(Vec<String>),
+}
+
+/// Split a list of entries into pages of embeds.
+///
+/// Entries are either lines, which are joined into the description of each
+/// page, or fields. They're packed into as few pages as possible without
+/// exceeding any of the limits of an embed, and each page has its number
+/// stamped into the footer in the form of "Page N/M". If the footer of the
+/// template is too long to fit the label, it's truncated with an ellipsis.
+///
+/// Page boundaries only depend on the template and entries, so a paginator
+/// created from the same input always produces the same pages. This allows
+/// a single page to be rebuilt with [`page`], such as in response to a button
+/// being pressed, without building every other page.
+///
+/// # Examples
+///
+/// Create a leaderboard with 10 entries on each page:
+///
+/// ```
+/// use twilight_embed_builder::{EmbedBuilder, Paginator};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let lines = (1..=25).map(|rank| format!("{rank}. twilight"));
+/// let paginator = Paginator::lines(EmbedBuilder::new().title("Leaderboard"), lines).per_page(10);
+///
+/// assert_eq!(3, paginator.len());
+///
+/// let embed = paginator.page(2).unwrap().build()?;
+/// assert_eq!("Page 3/3", embed.footer.unwrap().text);
+/// # Ok(()) }
+/// ```
+///
+/// [`page`]: Self::page
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "pages must be retrieved from the paginator"]
+pub struct Paginator {
+    entries: Entries,
+    pages: Vec<Range<usize>>,
+    per_page: usize,
+    template: EmbedBuilder,
+}
+
+impl Paginator {
+    /// Create a paginator that appends fields to the fields of the template.
+    ///
+    /// If the template already has [`EMBED_FIELD_LIMIT`] fields then there's
+    /// no room for any entries, and there are no pages.
+    ///
+    /// [`EMBED_FIELD_LIMIT`]: EmbedBuilder::EMBED_FIELD_LIMIT
+    pub fn fields(
+        template: EmbedBuilder,
+        fields: impl IntoIterator<Item = impl Into<EmbedField>>,
+    ) -> Self {
+        Self::new(
+            template,
+            Entries::Fields(fields.into_iter().map(Into::into).collect()),
+        )
+    }
+
+    /// Create a paginator that joins lines into the description of each page.
+    ///
+    /// If the template has a description then it is kept at the top of each
+    /// page, followed by an empty line.
+    pub fn lines(
+        template: EmbedBuilder,
+        lines: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self::new(
+            template,
+            Entries::Lines(lines.into_iter().map(Into::into).collect()),
+        )
+    }
+
+    fn new(template: EmbedBuilder, entries: Entries) -> Self {
+        let mut paginator = Self {
+            entries,
+            pages: Vec::new(),
+            per_page: usize::MAX,
+            template,
+        };
+
+        paginator.fit_footer();
+        paginator.paginate();
+
+        paginator
+    }
+
+    /// Set the maximum number of entries on each page.
+    ///
+    /// Pages may still hold fewer entries if they would otherwise exceed a
+    /// limit of the embed. A value of 0 is treated as 1.
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        self.per_page = per_page.max(1);
+        self.paginate();
+
+        self
+    }
+
+    /// Whether there are no pages, which is the case when there are no
+    /// entries or no room for them.
+    #[must_use = "retrieving whether there are pages has no effect if left unused"]
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty()
+    }
+
+    /// Number of pages.
+    #[must_use = "retrieving the number of pages has no effect if left unused"]
+    pub fn len(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Create the builder of the page at a zero-based index.
+    ///
+    /// Returns [`None`] if there is no page at the index.
+    ///
+    /// The builder is within every limit of an embed unless a single entry
+    /// is too long to fit on a page by itself, in which case building it
+    /// returns the matching [`EmbedError`].
+    ///
+    /// [`EmbedError`]: crate::EmbedError
+    #[must_use = "creating a page has no effect if left unused"]
+    pub fn page(&self, index: usize) -> Option<EmbedBuilder> {
+        let range = self.pages.get(index)?.clone();
+        let mut embed = self.template.0.clone();
+
+        match &self.entries {
+            Entries::Fields(fields) => embed.fields.extend_from_slice(&fields[range]),
+            Entries::Lines(lines) => {
+                let body = lines[range].join("\n");
+
+                embed.description = Some(match embed.description.take() {
+                    Some(header) => format!("{header}\n\n{body}"),
+                    None => body,
+                });
+            }
+        }
+
+        let label = format!("Page {}/{}", index + 1, self.pages.len());
+
+        if let Some(footer) = embed.footer.as_mut() {
+            footer.text.push_str(LABEL_SEPARATOR);
+            footer.text.push_str(&label);
+        } else {
+            embed.footer = Some(EmbedFooter {
+                icon_url: None,
+                proxy_icon_url: None,
+                text: label,
+            });
+        }
+
+        Some(EmbedBuilder(embed, self.template.1))
+    }
+
+    /// Range of entries on the page at a zero-based index.
+    ///
+    /// Returns [`None`] if there is no page at the index.
+    #[must_use = "retrieving the entries of a page has no effect if left unused"]
+    pub fn page_entries(&self, index: usize) -> Option<Range<usize>> {
+        self.pages.get(index).cloned()
+    }
+
+    /// Iterator over the builders of every page.
+    pub fn pages(&self) -> impl Iterator<Item = EmbedBuilder> + '_ {
+        (0..self.pages.len()).filter_map(move |index| self.page(index))
+    }
+
+    /// Length of the page label, including its separator from the footer text
+    /// of the template.
+    ///
+    /// The label is measured at its longest so that page boundaries don't
+    /// depend on the number of pages.
+    fn label_length(&self) -> usize {
+        let entry_count = match &self.entries {
+            Entries::Fields(fields) => fields.len(),
+            Entries::Lines(lines) => lines.len(),
+        };
+        let label_length = format!("Page {entry_count}/{entry_count}").len();
+
+        if self.template.0.footer.is_some() {
+            label_length + self.template.1.count(LABEL_SEPARATOR)
+        } else {
+            label_length
+        }
+    }
+
+    /// Truncate the footer text of the template so that the page label fits
+    /// within [`FOOTER_TEXT_LENGTH_LIMIT`].
+    ///
+    /// [`FOOTER_TEXT_LENGTH_LIMIT`]: EmbedBuilder::FOOTER_TEXT_LENGTH_LIMIT
+    fn fit_footer(&mut self) {
+        let limit = EmbedBuilder::FOOTER_TEXT_LENGTH_LIMIT.saturating_sub(self.label_length());
+        let counting = self.template.1;
+
+        if let Some(footer) = self.template.0.footer.as_mut() {
+            if let Some(text) = truncate::truncate_text(&footer.text, limit, "…", counting) {
+                footer.text = text;
+            }
+        }
+    }
+
+    /// Calculate the page boundaries.
+    fn paginate(&mut self) {
+        let label_length = self.label_length();
+        let template = &self.template.0;
+        let counting = self.template.1;
+
+        let available = EmbedBuilder::EMBED_LENGTH_LIMIT
+            .saturating_sub(EmbedPart::total_length(template, counting) + label_length);
+
+        self.pages = match &self.entries {
+            Entries::Fields(fields) => pack(
+                fields
+                    .iter()
+                    .map(|field| counting.count(&field.name) + counting.count(&field.value)),
+                0,
+                self.per_page
+                    .min(EmbedBuilder::EMBED_FIELD_LIMIT.saturating_sub(template.fields.len())),
+                available,
+            ),
+            Entries::Lines(lines) => {
+                // The header is separated from the lines by an empty line,
+                // which the template's total length doesn't include.
+                let (header_length, header_separator) = template
+                    .description
+                    .as_ref()
+                    .map_or((0, 0), |description| (counting.count(description), 2));
+
+                pack(
+                    lines.iter().map(|line| counting.count(line)),
+                    1,
+                    self.per_page,
+                    available.saturating_sub(header_separator).min(
+                        EmbedBuilder::DESCRIPTION_LENGTH_LIMIT
+                            .saturating_sub(header_length + header_separator),
+                    ),
+                )
+            }
+        };
+    }
+}
+
+/// Pack entries of the given lengths into pages with at most a number of
+/// entries and a combined length, including the separator between entries.
+///
+/// An entry that is too long to share a page is placed on a page by itself.
+/// There are no pages if no entries are allowed on a page.
+fn pack(
+    lengths: impl Iterator<Item = usize>,
+    separator: usize,
+    max_entries: usize,
+    max_length: usize,
+) -> Vec<Range<usize>> {
+    let mut pages = Vec::new();
+
+    if max_entries == 0 {
+        return pages;
+    }
+
+    let mut start = 0;
+    let mut end = 0;
+    let mut length = 0;
+
+    for (idx, entry_length) in lengths.enumerate() {
+        end = idx + 1;
+
+        if idx == start {
+            length = entry_length;
+
+            continue;
+        }
+
+        if idx - start >= max_entries || length + separator + entry_length > max_length {
+            pages.push(start..idx);
+            start = idx;
+            length = entry_length;
+        } else {
+            length += separator + entry_length;
+        }
+    }
+
+    if end > start {
+        pages.push(start..end);
+    }
+
+    pages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pack, Paginator};
+    use crate::{EmbedAuthorBuilder, EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(Paginator: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn pack_limits() {
+        assert_eq!(pack([1, 1, 1].into_iter(), 0, 2, 100), [0..2, 2..3]);
+        assert_eq!(pack([3, 3, 3].into_iter(), 1, 10, 7), [0..2, 2..3]);
+        assert_eq!(pack([9, 1].into_iter(), 0, 10, 5), [0..1, 1..2]);
+        assert!(pack([].into_iter(), 0, 10, 5).is_empty());
+        assert!(pack([1, 1].into_iter(), 0, 0, 100).is_empty());
+    }
+
+    #[test]
+    fn lines() {
+        let line = "a".repeat(99);
+        let paginator = Paginator::lines(
+            EmbedBuilder::new().description("header"),
+            vec![line.as_str(); 100],
+        );
+
+        assert_eq!(3, paginator.len());
+
+        for (index, page) in paginator.pages().enumerate() {
+            let embed = page.build().unwrap();
+            let description = embed.description.unwrap();
+
+            assert!(description.starts_with("header\n\na"));
+            assert_eq!(format!("Page {}/3", index + 1), embed.footer.unwrap().text);
+        }
+
+        assert_eq!(Some(0..40), paginator.page_entries(0));
+        assert!(paginator.page(3).is_none());
+    }
+
+    #[test]
+    fn lines_total_length() {
+        // The template leaves room for 3971 characters on each page, which
+        // the lines fill exactly if the empty line after the header isn't
+        // counted.
+        let template = EmbedBuilder::new()
+            .author(EmbedAuthorBuilder::new("a".repeat(256)))
+            .description("header")
+            .footer(EmbedFooterBuilder::new("f".repeat(1500)))
+            .title("t".repeat(256));
+        let paginator = Paginator::lines(template, ["a".repeat(3960), "b".repeat(10)]);
+
+        assert_eq!(2, paginator.len());
+
+        for page in paginator.pages() {
+            assert!(page.length() <= EmbedBuilder::EMBED_LENGTH_LIMIT);
+            page.build().unwrap();
+        }
+    }
+
+    #[test]
+    fn fields() {
+        let fields = (0..60).map(|idx| EmbedFieldBuilder::new(idx.to_string(), "value"));
+        let paginator = Paginator::fields(
+            EmbedBuilder::new()
+                .field(EmbedFieldBuilder::new("pinned", "value"))
+                .footer(EmbedFooterBuilder::new("twilight")),
+            fields,
+        );
+
+        assert_eq!(3, paginator.len());
+        assert_eq!(Some(0..24), paginator.page_entries(0));
+
+        let embed = paginator.page(1).unwrap().build().unwrap();
+        assert_eq!(EmbedBuilder::EMBED_FIELD_LIMIT, embed.fields.len());
+        assert_eq!("pinned", embed.fields[0].name);
+        assert_eq!("24", embed.fields[1].name);
+        assert_eq!("twilight • Page 2/3", embed.footer.unwrap().text);
+    }
+
+    #[test]
+    fn fields_full_template() {
+        let template = EmbedBuilder::new().fields(
+            (0..EmbedBuilder::EMBED_FIELD_LIMIT)
+                .map(|idx| EmbedFieldBuilder::new(idx.to_string(), "value")),
+        );
+        let paginator = Paginator::fields(template, [EmbedFieldBuilder::new("name", "value")]);
+
+        assert!(paginator.is_empty());
+        assert!(paginator.page(0).is_none());
+    }
+
+    #[test]
+    fn footer_full_template() {
+        let template = EmbedBuilder::new().footer(EmbedFooterBuilder::new("f".repeat(2040)));
+        let paginator = Paginator::fields(
+            template,
+            (0..30).map(|idx| EmbedFieldBuilder::new(idx.to_string(), "value")),
+        );
+
+        assert_eq!(2, paginator.len());
+
+        for page in paginator.pages() {
+            let text = page.build().unwrap().footer.unwrap().text;
+
+            assert!(text.chars().count() <= EmbedBuilder::FOOTER_TEXT_LENGTH_LIMIT);
+            assert!(text.starts_with("ffff"));
+            assert!(text.contains("…"));
+        }
+
+        let text = Paginator::lines(
+            EmbedBuilder::new().footer(EmbedFooterBuilder::new("f".repeat(2048))),
+            ["line"],
+        )
+        .page(0)
+        .unwrap()
+        .build()
+        .unwrap()
+        .footer
+        .unwrap()
+        .text;
+
+        assert_eq!(format!("{}… • Page 1/1", "f".repeat(2036)), text);
+    }
+
+    #[test]
+    fn per_page() {
+        let paginator =
+            Paginator::lines(EmbedBuilder::new(), (0..25).map(|idx| idx.to_string())).per_page(10);
+
+        assert_eq!(3, paginator.len());
+        assert_eq!(Some(20..25), paginator.page_entries(2));
+        assert!(Paginator::lines(EmbedBuilder::new(), Vec::<String>::new()).is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+//! Textual parts of an embed.
+
+use crate::length::LengthCounting;
+use twilight_model::channel::embed::Embed;
+
+/// Textual part of an embed that is subject to a length limit.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EmbedPart {
+    /// Name of the author.
+    AuthorName,
+    /// Description.
+    Description,
+    /// Name of the field at the contained index.
+    FieldName(usize),
+    /// Value of the field at the contained index.
+    FieldValue(usize),
+    /// Text of the footer.
+    FooterText,
+    /// Title.
+    Title,
+}
+
+impl EmbedPart {
+    /// Maximum length of the part.
+    ///
+    /// This is the matching `*_LENGTH_LIMIT` constant on [`EmbedBuilder`].
+    ///
+    /// [`EmbedBuilder`]: crate::EmbedBuilder
+    #[must_use = "retrieving the limit has no effect if left unused"]
+    pub const fn limit(self) -> usize {
+        match self {
+            Self::AuthorName => crate::EmbedBuilder::AUTHOR_NAME_LENGTH_LIMIT,
+            Self::Description => crate::EmbedBuilder::DESCRIPTION_LENGTH_LIMIT,
+            Self::FieldName(_) => crate::EmbedBuilder::FIELD_NAME_LENGTH_LIMIT,
+            Self::FieldValue(_) => crate::EmbedBuilder::FIELD_VALUE_LENGTH_LIMIT,
+            Self::FooterText => crate::EmbedBuilder::FOOTER_TEXT_LENGTH_LIMIT,
+            Self::Title => crate::EmbedBuilder::TITLE_LENGTH_LIMIT,
+        }
+    }
+
+    /// Parts that are present in an embed, in the order they're validated.
+    pub(crate) fn present(embed: &Embed) -> Vec<Self> {
+        let mut parts = Vec::with_capacity(4 + embed.fields.len() * 2);
+
+        if embed.author.is_some() {
+            parts.push(Self::AuthorName);
+        }
+
+        if embed.description.is_some() {
+            parts.push(Self::Description);
+        }
+
+        if embed.footer.is_some() {
+            parts.push(Self::FooterText);
+        }
+
+        for idx in 0..embed.fields.len() {
+            parts.push(Self::FieldName(idx));
+            parts.push(Self::FieldValue(idx));
+        }
+
+        if embed.title.is_some() {
+            parts.push(Self::Title);
+        }
+
+        parts
+    }
+
+    /// Total length of the text in an embed that counts towards
+    /// [`EmbedBuilder::EMBED_LENGTH_LIMIT`].
+    ///
+    /// [`EmbedBuilder::EMBED_LENGTH_LIMIT`]: crate::EmbedBuilder::EMBED_LENGTH_LIMIT
+    pub(crate) fn total_length(embed: &Embed, counting: LengthCounting) -> usize {
+        Self::present(embed)
+            .into_iter()
+            .filter_map(|part| part.text(embed))
+            .map(|text| counting.count(text))
+            .sum()
+    }
+
+    /// Immutable reference to the text of the part in an embed, if present.
+    pub(crate) fn text(self, embed: &Embed) -> Option<&str> {
+        match self {
+            Self::AuthorName => embed.author.as_ref().map(|author| author.name.as_str()),
+            Self::Description => embed.description.as_deref(),
+            Self::FieldName(idx) => embed.fields.get(idx).map(|field| field.name.as_str()),
+            Self::FieldValue(idx) => embed.fields.get(idx).map(|field| field.value.as_str()),
+            Self::FooterText => embed.footer.as_ref().map(|footer| footer.text.as_str()),
+            Self::Title => embed.title.as_deref(),
+        }
+    }
+
+    /// Mutable reference to the text of the part in an embed, if present.
+    pub(crate) fn text_mut(self, embed: &mut Embed) -> Option<&mut String> {
+        match self {
+            Self::AuthorName => embed.author.as_mut().map(|author| &mut author.name),
+            Self::Description => embed.description.as_mut(),
+            Self::FieldName(idx) => embed.fields.get_mut(idx).map(|field| &mut field.name),
+            Self::FieldValue(idx) => embed.fields.get_mut(idx).map(|field| &mut field.value),
+            Self::FooterText => embed.footer.as_mut().map(|footer| &mut footer.text),
+            Self::Title => embed.title.as_mut(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmbedPart;
+    use crate::{EmbedBuilder, EmbedFieldBuilder};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(EmbedPart: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn present() {
+        let embed = EmbedBuilder::new()
+            .title("title")
+            .field(EmbedFieldBuilder::new("name", "value"))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            EmbedPart::present(&embed),
+            [
+                EmbedPart::FieldName(0),
+                EmbedPart::FieldValue(0),
+                EmbedPart::Title
+            ]
+        );
+        assert_eq!(Some("value"), EmbedPart::FieldValue(0).text(&embed));
+        assert_eq!(None, EmbedPart::Description.text(&embed));
+    }
+}
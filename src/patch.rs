@@ -0,0 +1,928 @@
+//! Update embeds incrementally with patches.
+
+use crate::{
+    diff::{Change, EmbedDiff},
+    Color, EmbedAuthorBuilder, EmbedBuilder, EmbedError, EmbedFieldBuilder, EmbedFooterBuilder,
+    ImageSource,
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::FromIterator,
+};
+use twilight_model::{
+    channel::embed::{Embed, EmbedField, EmbedImage, EmbedThumbnail},
+    util::Timestamp,
+};
+
+/// Error applying or merging a patch.
+#[derive(Debug)]
+pub struct PatchError {
+    kind: PatchErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl PatchError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PatchErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (PatchErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for PatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PatchErrorType::Conflict { first, second } => {
+                f.write_str("operation ")?;
+                Display::fmt(first, f)?;
+                f.write_str(" conflicts with operation ")?;
+
+                Display::fmt(second, f)
+            }
+            PatchErrorType::EmbedInvalid => f.write_str("the patched embed is invalid"),
+            PatchErrorType::FieldMissing { index, operation } => {
+                f.write_str("operation ")?;
+                Display::fmt(operation, f)?;
+                f.write_str(" refers to field ")?;
+                Display::fmt(index, f)?;
+
+                f.write_str(", which doesn't exist")
+            }
+        }
+    }
+}
+
+impl Error for PatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`PatchError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PatchErrorType {
+    /// Two operations conflict.
+    ///
+    /// When applying a patch, operations conflict when they set the same part
+    /// of the embed. When merging patches, operations also conflict when they
+    /// refer to the same field, or when one of them adds, moves or removes a
+    /// field and the other refers to a field by its index, since the indices
+    /// of the other patch would no longer refer to the same fields.
+    Conflict {
+        /// Index of the first operation.
+        ///
+        /// When merging, this is an index into the patch being merged into.
+        first: usize,
+        /// Index of the second operation.
+        ///
+        /// When merging, this is an index into the patch being merged.
+        second: usize,
+    },
+    /// The patched embed failed to build.
+    ///
+    /// The source of the error is the [`EmbedError`] returned while building
+    /// it.
+    EmbedInvalid,
+    /// An operation refers to a field that doesn't exist.
+    FieldMissing {
+        /// Index of the field.
+        index: usize,
+        /// Index of the operation.
+        operation: usize,
+    },
+}
+
+/// Operation updating part of an embed.
+///
+/// Operations referring to fields by index refer to the fields as they are
+/// after the previous operations of the patch have been applied.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case", tag = "op")
+)]
+#[non_exhaustive]
+pub enum PatchOperation {
+    /// Add a field after the last field.
+    AppendField {
+        /// Field to add.
+        field: EmbedFieldBuilder,
+    },
+    /// Insert a field at an index, shifting the fields after it.
+    InsertField {
+        /// Field to insert.
+        field: EmbedFieldBuilder,
+        /// Index to insert the field at, which may be the number of fields.
+        index: usize,
+    },
+    /// Move a field to another index, shifting the fields in between.
+    MoveField {
+        /// Index of the field.
+        from: usize,
+        /// Index to move the field to.
+        to: usize,
+    },
+    /// Remove a field, shifting the fields after it.
+    RemoveField {
+        /// Index of the field.
+        index: usize,
+    },
+    /// Replace a field.
+    ReplaceField {
+        /// New field.
+        field: EmbedFieldBuilder,
+        /// Index of the field.
+        index: usize,
+    },
+    /// Set or clear the author.
+    SetAuthor {
+        /// New author.
+        author: Option<EmbedAuthorBuilder>,
+    },
+    /// Set or clear the color.
+    SetColor {
+        /// New color.
+        color: Option<Color>,
+    },
+    /// Set or clear the description.
+    SetDescription {
+        /// New description.
+        description: Option<String>,
+    },
+    /// Set whether a field is inline.
+    SetFieldInline {
+        /// Index of the field.
+        index: usize,
+        /// Whether the field is inline.
+        inline: bool,
+    },
+    /// Set the name of a field.
+    SetFieldName {
+        /// Index of the field.
+        index: usize,
+        /// New name.
+        name: String,
+    },
+    /// Set the value of a field.
+    SetFieldValue {
+        /// Index of the field.
+        index: usize,
+        /// New value.
+        value: String,
+    },
+    /// Set or clear the footer.
+    SetFooter {
+        /// New footer.
+        footer: Option<EmbedFooterBuilder>,
+    },
+    /// Set or clear the image.
+    SetImage {
+        /// New image.
+        image: Option<ImageSource>,
+    },
+    /// Set or clear the thumbnail.
+    SetThumbnail {
+        /// New thumbnail.
+        thumbnail: Option<ImageSource>,
+    },
+    /// Set or clear the timestamp.
+    SetTimestamp {
+        /// New timestamp.
+        timestamp: Option<Timestamp>,
+    },
+    /// Set or clear the title.
+    SetTitle {
+        /// New title.
+        title: Option<String>,
+    },
+    /// Set or clear the URL of the title.
+    SetUrl {
+        /// New URL.
+        url: Option<String>,
+    },
+}
+
+impl PatchOperation {
+    /// Apply the operation to an embed, returning the index of the missing
+    /// field if the operation refers to one.
+    fn apply(&self, embed: &mut Embed) -> Result<(), usize> {
+        match self {
+            Self::AppendField { field } => embed.fields.push(field.clone().build()),
+            Self::InsertField { field, index } => {
+                if *index > embed.fields.len() {
+                    return Err(*index);
+                }
+
+                embed.fields.insert(*index, field.clone().build());
+            }
+            Self::MoveField { from, to } => {
+                let length = embed.fields.len();
+
+                if *from >= length {
+                    return Err(*from);
+                }
+
+                if *to >= length {
+                    return Err(*to);
+                }
+
+                let field = embed.fields.remove(*from);
+                embed.fields.insert(*to, field);
+            }
+            Self::RemoveField { index } => {
+                field_mut(embed, *index)?;
+                embed.fields.remove(*index);
+            }
+            Self::ReplaceField { field, index } => {
+                *field_mut(embed, *index)? = field.clone().build();
+            }
+            Self::SetAuthor { author } => {
+                embed.author = author.clone().map(EmbedAuthorBuilder::build);
+            }
+            Self::SetColor { color } => embed.color = color.map(Color::get),
+            Self::SetDescription { description } => embed.description.clone_from(description),
+            Self::SetFieldInline { index, inline } => field_mut(embed, *index)?.inline = *inline,
+            Self::SetFieldName { index, name } => field_mut(embed, *index)?.name.clone_from(name),
+            Self::SetFieldValue { index, value } => {
+                field_mut(embed, *index)?.value.clone_from(value);
+            }
+            Self::SetFooter { footer } => {
+                embed.footer = footer.clone().map(EmbedFooterBuilder::build);
+            }
+            Self::SetImage { image } => {
+                embed.image = image.as_ref().map(|image| EmbedImage {
+                    height: None,
+                    proxy_url: None,
+                    url: image.0.clone(),
+                    width: None,
+                });
+            }
+            Self::SetThumbnail { thumbnail } => {
+                embed.thumbnail = thumbnail.as_ref().map(|thumbnail| EmbedThumbnail {
+                    height: None,
+                    proxy_url: None,
+                    url: thumbnail.0.clone(),
+                    width: None,
+                });
+            }
+            Self::SetTimestamp { timestamp } => embed.timestamp = *timestamp,
+            Self::SetTitle { title } => embed.title.clone_from(title),
+            Self::SetUrl { url } => embed.url.clone_from(url),
+        }
+
+        Ok(())
+    }
+
+    /// Part of the embed that the operation updates.
+    const fn target(&self) -> Target {
+        match self {
+            Self::AppendField { .. } => Target::Appended,
+            Self::InsertField { .. } | Self::MoveField { .. } | Self::RemoveField { .. } => {
+                Target::Fields
+            }
+            Self::ReplaceField { index, .. }
+            | Self::SetFieldInline { index, .. }
+            | Self::SetFieldName { index, .. }
+            | Self::SetFieldValue { index, .. } => Target::Field(*index),
+            Self::SetAuthor { .. } => Target::Author,
+            Self::SetColor { .. } => Target::Color,
+            Self::SetDescription { .. } => Target::Description,
+            Self::SetFooter { .. } => Target::Footer,
+            Self::SetImage { .. } => Target::Image,
+            Self::SetThumbnail { .. } => Target::Thumbnail,
+            Self::SetTimestamp { .. } => Target::Timestamp,
+            Self::SetTitle { .. } => Target::Title,
+            Self::SetUrl { .. } => Target::Url,
+        }
+    }
+}
+
+/// Part of an embed that an operation updates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Target {
+    Appended,
+    Author,
+    Color,
+    Description,
+    Field(usize),
+    Fields,
+    Footer,
+    Image,
+    Thumbnail,
+    Timestamp,
+    Title,
+    Url,
+}
+
+impl Target {
+    /// Whether the target is fields referred to by their index.
+    const fn is_indexed_field(self) -> bool {
+        matches!(self, Self::Field(_) | Self::Fields)
+    }
+}
+
+/// Ordered operations updating an embed.
+///
+/// Patches can be serialized with the `serde` feature, created from an
+/// [`EmbedDiff`] to reproduce the changes it found, and merged with patches
+/// created concurrently from the same embed.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{
+///     patch::PatchOperation, EmbedBuilder, EmbedFieldBuilder, EmbedPatch, ImageSource,
+/// };
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let builder = EmbedBuilder::new()
+///     .title("Server status")
+///     .field(EmbedFieldBuilder::new("Players", "12"))
+///     .thumbnail(ImageSource::url("https://example.com/status.png")?);
+///
+/// let patch = EmbedPatch::new()
+///     .operation(PatchOperation::SetFieldValue {
+///         index: 0,
+///         value: "13".to_owned(),
+///     })
+///     .operation(PatchOperation::AppendField {
+///         field: EmbedFieldBuilder::new("Uptime", "3 days"),
+///     })
+///     .operation(PatchOperation::SetThumbnail { thumbnail: None });
+///
+/// let embed = patch.apply(builder)?;
+///
+/// assert_eq!("13", embed.fields[0].value);
+/// assert_eq!("Uptime", embed.fields[1].name);
+/// assert!(embed.thumbnail.is_none());
+/// # Ok(()) }
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+#[must_use = "must be applied to an embed"]
+pub struct EmbedPatch(Vec<PatchOperation>);
+
+impl EmbedPatch {
+    /// Create a new patch without any operations.
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Apply the patch to an embed builder and build the patched embed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`apply_to_builder`].
+    ///
+    /// Returns a [`PatchErrorType::EmbedInvalid`] error type if the patched
+    /// embed fails to build.
+    ///
+    /// [`apply_to_builder`]: Self::apply_to_builder
+    pub fn apply(&self, builder: EmbedBuilder) -> Result<Embed, PatchError> {
+        self.apply_to_builder(builder)?
+            .build()
+            .map_err(|source: EmbedError| PatchError {
+                kind: PatchErrorType::EmbedInvalid,
+                source: Some(Box::new(source)),
+            })
+    }
+
+    /// Apply the patch to an embed builder without building it.
+    ///
+    /// Operations update the embed directly rather than through the methods
+    /// of [`EmbedBuilder`]. Operations contain builders and validated types
+    /// such as [`Color`] and [`ImageSource`], but text isn't checked against
+    /// its limits until the builder is built.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PatchErrorType::Conflict`] error type if two operations set
+    /// the same part of the embed.
+    ///
+    /// Returns a [`PatchErrorType::FieldMissing`] error type if an operation
+    /// refers to a field that doesn't exist.
+    pub fn apply_to_builder(&self, mut builder: EmbedBuilder) -> Result<EmbedBuilder, PatchError> {
+        for (second, operation) in self.0.iter().enumerate() {
+            let target = operation.target();
+
+            if target.is_indexed_field() || target == Target::Appended {
+                continue;
+            }
+
+            if let Some(first) = self.0[..second]
+                .iter()
+                .position(|earlier| earlier.target() == target)
+            {
+                return Err(PatchError {
+                    kind: PatchErrorType::Conflict { first, second },
+                    source: None,
+                });
+            }
+        }
+
+        for (idx, operation) in self.0.iter().enumerate() {
+            operation
+                .apply(&mut builder.0)
+                .map_err(|index| PatchError {
+                    kind: PatchErrorType::FieldMissing {
+                        index,
+                        operation: idx,
+                    },
+                    source: None,
+                })?;
+        }
+
+        Ok(builder)
+    }
+
+    /// Whether the patch has no operations.
+    #[must_use = "retrieving whether there are operations has no effect if left unused"]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Merge a patch created from the same embed into this patch.
+    ///
+    /// The operations of the other patch are applied after the operations of
+    /// this patch, and operations that both patches contain are only applied
+    /// once. Appending fields never conflicts.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PatchErrorType::Conflict`] error type if the patches set
+    /// the same part of the embed or refer to the same field differently, or
+    /// if one patch adds, moves or removes a field while the other refers to a
+    /// field by its index.
+    pub fn merge(mut self, other: Self) -> Result<Self, PatchError> {
+        let mut merged = Vec::with_capacity(other.0.len());
+
+        for (second, operation) in other.0.into_iter().enumerate() {
+            let target = operation.target();
+            let mut duplicate = false;
+
+            for (first, existing) in self.0.iter().enumerate() {
+                let existing_target = existing.target();
+
+                if *existing == operation && target != Target::Appended {
+                    duplicate = true;
+
+                    continue;
+                }
+
+                let conflict = if target.is_indexed_field() && existing_target.is_indexed_field() {
+                    target == Target::Fields
+                        || existing_target == Target::Fields
+                        || target == existing_target
+                } else {
+                    target != Target::Appended && target == existing_target
+                };
+
+                if conflict {
+                    return Err(PatchError {
+                        kind: PatchErrorType::Conflict { first, second },
+                        source: None,
+                    });
+                }
+            }
+
+            if !duplicate {
+                merged.push(operation);
+            }
+        }
+
+        self.0.extend(merged);
+
+        Ok(self)
+    }
+
+    /// Add an operation after the existing operations.
+    pub fn operation(mut self, operation: PatchOperation) -> Self {
+        self.0.push(operation);
+
+        self
+    }
+
+    /// Immutable reference to the operations.
+    #[must_use = "retrieving the operations has no effect if left unused"]
+    pub fn operations(&self) -> &[PatchOperation] {
+        &self.0
+    }
+}
+
+impl From<EmbedDiff> for EmbedPatch {
+    /// Create a patch that makes the changes of a diff, turning the previous
+    /// embed into the new embed.
+    ///
+    /// Image and icon URLs are taken from the new embed as they are, without
+    /// checking whether they are valid image sources. A color that isn't
+    /// valid for [`Color::new`] is cleared, since a [`Color`] can't hold it.
+    fn from(diff: EmbedDiff) -> Self {
+        let mut operations = Vec::new();
+        let mut fields = FieldChanges::default();
+
+        for change in diff {
+            let operation = match change {
+                Change::Author { new, .. } => PatchOperation::SetAuthor {
+                    author: new.map(EmbedAuthorBuilder::from),
+                },
+                Change::Color { new, .. } => PatchOperation::SetColor {
+                    color: new.and_then(|color| Color::new(color).ok()),
+                },
+                Change::Description { new, .. } => {
+                    PatchOperation::SetDescription { description: new }
+                }
+                Change::FieldAdded { field, index } => {
+                    fields.added.push((index, field));
+
+                    continue;
+                }
+                Change::FieldChanged { index, new, old } => {
+                    fields.changed.push((index, old, new));
+
+                    continue;
+                }
+                Change::FieldMoved { from, to, .. } => {
+                    fields.moved.push((from, to));
+
+                    continue;
+                }
+                Change::FieldRemoved { index, .. } => {
+                    fields.removed.push(index);
+
+                    continue;
+                }
+                Change::Footer { new, .. } => PatchOperation::SetFooter {
+                    footer: new.map(EmbedFooterBuilder::from),
+                },
+                Change::Image { new, .. } => PatchOperation::SetImage {
+                    image: new.map(ImageSource),
+                },
+                Change::Thumbnail { new, .. } => PatchOperation::SetThumbnail {
+                    thumbnail: new.map(ImageSource),
+                },
+                Change::Timestamp { new, .. } => PatchOperation::SetTimestamp { timestamp: new },
+                Change::Title { new, .. } => PatchOperation::SetTitle { title: new },
+                Change::Url { new, .. } => PatchOperation::SetUrl { url: new },
+            };
+
+            operations.push(operation);
+        }
+
+        fields.operations(&mut operations);
+
+        Self(operations)
+    }
+}
+
+impl FromIterator<PatchOperation> for EmbedPatch {
+    fn from_iter<T: IntoIterator<Item = PatchOperation>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Changes to fields found by a diff.
+#[derive(Default)]
+struct FieldChanges {
+    /// Index in the new embed and added field.
+    added: Vec<(usize, EmbedField)>,
+    /// Index in the new embed, previous field and new field.
+    changed: Vec<(usize, EmbedField, EmbedField)>,
+    /// Indices in the previous and new embed.
+    moved: Vec<(usize, usize)>,
+    /// Indices in the previous embed.
+    removed: Vec<usize>,
+}
+
+impl FieldChanges {
+    /// Add operations making the changes.
+    ///
+    /// Fields are removed first, then added and moved into place from the
+    /// first field to the last, and changed last. Diffs don't record fields
+    /// that stayed in order, so only as many fields as the changes refer to
+    /// are tracked: those that stayed in order keep their relative order, so
+    /// they fill the positions that aren't added or moved to in order.
+    fn operations(mut self, operations: &mut Vec<PatchOperation>) {
+        self.removed.sort_unstable();
+
+        for index in self.removed.iter().rev() {
+            operations.push(PatchOperation::RemoveField { index: *index });
+        }
+
+        let mut new_length = self
+            .added
+            .iter()
+            .map(|(index, _)| index + 1)
+            .chain(self.moved.iter().map(|(_, to)| to + 1))
+            .max()
+            .unwrap_or(0);
+        let mut old_length = new_length + self.removed.len() - self.added.len();
+        let old_needed = self
+            .removed
+            .iter()
+            .map(|index| index + 1)
+            .chain(self.moved.iter().map(|(from, _)| from + 1))
+            .max()
+            .unwrap_or(0);
+
+        if old_length < old_needed {
+            new_length += old_needed - old_length;
+            old_length = old_needed;
+        }
+
+        let mut current: Vec<Option<usize>> = (0..old_length)
+            .filter(|index| !self.removed.contains(index))
+            .map(Some)
+            .collect();
+        let mut in_order = current
+            .clone()
+            .into_iter()
+            .flatten()
+            .filter(|index| !self.moved.iter().any(|(from, _)| from == index));
+
+        for index in 0..new_length {
+            if let Some(position) = self.added.iter().position(|(to, _)| *to == index) {
+                let (_, field) = self.added.swap_remove(position);
+                operations.push(PatchOperation::InsertField {
+                    field: field.into(),
+                    index,
+                });
+                current.insert(index, None);
+
+                continue;
+            }
+
+            let source = self
+                .moved
+                .iter()
+                .find(|(_, to)| *to == index)
+                .map(|(from, _)| *from)
+                .or_else(|| in_order.next());
+            let position = source.and_then(|source| {
+                current
+                    .iter()
+                    .position(|existing| *existing == Some(source))
+            });
+
+            if let Some(position) = position {
+                if position != index {
+                    operations.push(PatchOperation::MoveField {
+                        from: position,
+                        to: index,
+                    });
+                    let field = current.remove(position);
+                    current.insert(index, field);
+                }
+            }
+        }
+
+        for (index, old, new) in self.changed {
+            let operation = if old.name == new.name && old.inline == new.inline {
+                PatchOperation::SetFieldValue {
+                    index,
+                    value: new.value,
+                }
+            } else if old.value == new.value && old.inline == new.inline {
+                PatchOperation::SetFieldName {
+                    index,
+                    name: new.name,
+                }
+            } else if old.name == new.name && old.value == new.value {
+                PatchOperation::SetFieldInline {
+                    index,
+                    inline: new.inline,
+                }
+            } else {
+                PatchOperation::ReplaceField {
+                    field: new.into(),
+                    index,
+                }
+            };
+
+            operations.push(operation);
+        }
+    }
+}
+
+/// Mutable reference to a field, or the index if it doesn't exist.
+fn field_mut(embed: &mut Embed, index: usize) -> Result<&mut EmbedField, usize> {
+    embed.fields.get_mut(index).ok_or(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EmbedPatch, PatchError, PatchErrorType, PatchOperation};
+    use crate::{Color, EmbedBuilder, EmbedDiff, EmbedError, EmbedErrorType, EmbedFieldBuilder};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(PatchErrorType: Debug, Send, Sync);
+    assert_fields!(PatchErrorType::Conflict: first, second);
+    assert_fields!(PatchErrorType::FieldMissing: index, operation);
+    assert_impl_all!(PatchError: Error, Send, Sync);
+    assert_impl_all!(
+        EmbedPatch: Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_impl_all!(PatchOperation: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    fn builder(fields: &[&str]) -> EmbedBuilder {
+        fields.iter().fold(EmbedBuilder::new(), |builder, field| {
+            let (name, value) = field.split_once('=').unwrap_or((field, "value"));
+
+            builder.field(EmbedFieldBuilder::new(name, value))
+        })
+    }
+
+    fn title(title: &str) -> PatchOperation {
+        PatchOperation::SetTitle {
+            title: Some(title.to_owned()),
+        }
+    }
+
+    #[test]
+    fn field_missing() {
+        let error = EmbedPatch::new()
+            .operation(PatchOperation::RemoveField { index: 0 })
+            .operation(PatchOperation::RemoveField { index: 0 })
+            .apply(builder(&["a"]))
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            PatchErrorType::FieldMissing {
+                index: 0,
+                operation: 1
+            }
+        ));
+    }
+
+    #[test]
+    fn embed_invalid() {
+        let error = EmbedPatch::new()
+            .operation(title(""))
+            .apply(EmbedBuilder::new())
+            .unwrap_err();
+
+        assert!(matches!(error.kind(), PatchErrorType::EmbedInvalid));
+        let source = error
+            .into_source()
+            .unwrap()
+            .downcast::<EmbedError>()
+            .unwrap();
+        assert!(matches!(source.kind(), EmbedErrorType::TitleEmpty { .. }));
+    }
+
+    #[test]
+    fn conflicts() {
+        assert!(matches!(
+            EmbedPatch::new()
+                .operation(title("a"))
+                .operation(PatchOperation::SetDescription { description: None })
+                .operation(title("b"))
+                .apply(EmbedBuilder::new())
+                .unwrap_err()
+                .kind(),
+            PatchErrorType::Conflict {
+                first: 0,
+                second: 2
+            }
+        ));
+
+        let append = |name: &str| PatchOperation::AppendField {
+            field: EmbedFieldBuilder::new(name, "value"),
+        };
+        let merged = EmbedPatch::new()
+            .operation(title("a"))
+            .operation(append("a"))
+            .merge(
+                EmbedPatch::new()
+                    .operation(title("a"))
+                    .operation(append("b")),
+            )
+            .unwrap();
+        assert_eq!(3, merged.operations().len());
+
+        assert!(EmbedPatch::new()
+            .operation(title("a"))
+            .merge(EmbedPatch::new().operation(title("b")))
+            .is_err());
+        assert!(matches!(
+            EmbedPatch::new()
+                .operation(PatchOperation::SetFieldValue {
+                    index: 3,
+                    value: "value".to_owned(),
+                })
+                .merge(EmbedPatch::new().operation(PatchOperation::RemoveField { index: 0 }))
+                .unwrap_err()
+                .kind(),
+            PatchErrorType::Conflict {
+                first: 0,
+                second: 0
+            }
+        ));
+    }
+
+    #[test]
+    fn from_diff() {
+        let cases: &[(&[&str], &[&str])] = &[
+            (&["a", "b", "c", "d"], &["x", "c", "a", "e"]),
+            (&["a", "b", "c", "d", "e"], &["e", "d", "c", "b", "a"]),
+            (
+                &["a", "b", "c", "d", "e", "f"],
+                &["b", "a", "c", "d", "e", "f"],
+            ),
+            (&["a", "b", "c", "d", "e", "f"], &["a", "b", "c", "d", "f"]),
+            (&["a", "b", "c"], &["a=changed", "x", "b", "c", "y"]),
+            (&["a", "b", "c", "d"], &["d", "b=changed"]),
+            (&[], &["a", "b"]),
+            (&["a", "b"], &[]),
+        ];
+
+        for (old, new) in cases {
+            let (old, new) = (builder(old), builder(new));
+            let patch = EmbedPatch::from(EmbedDiff::new(&old, &new));
+            let patched = patch.apply_to_builder(old).unwrap();
+
+            assert_eq!(new.get_fields(), patched.get_fields(), "{patch:?}");
+        }
+    }
+
+    #[test]
+    fn from_diff_color() {
+        let old = EmbedBuilder::new().color(Color::RED);
+        let mut new = EmbedBuilder::new();
+        new.0.color = Some(0x01_00_00_00);
+
+        assert_eq!(
+            [PatchOperation::SetColor { color: None }],
+            EmbedPatch::from(EmbedDiff::new(&old, &new)).operations()
+        );
+
+        let new = EmbedBuilder::new().color(Color::BLUE);
+
+        assert_eq!(
+            [PatchOperation::SetColor {
+                color: Some(Color::BLUE)
+            }],
+            EmbedPatch::from(EmbedDiff::new(&old, &new)).operations()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> Result<(), Box<dyn Error>> {
+        let patch = EmbedPatch::new()
+            .operation(PatchOperation::SetFieldValue {
+                index: 3,
+                value: "value".to_owned(),
+            })
+            .operation(PatchOperation::SetThumbnail { thumbnail: None })
+            .operation(PatchOperation::SetColor {
+                color: Some(Color::RED),
+            });
+        let json = r#"[{"op":"set_field_value","index":3,"value":"value"},{"op":"set_thumbnail","thumbnail":null},{"op":"set_color","color":15548997}]"#;
+
+        assert_eq!(json, serde_json::to_string(&patch)?);
+        assert_eq!(patch, serde_json::from_str(json)?);
+        assert!(serde_json::from_str::<EmbedPatch>(
+            r#"[{"op":"set_image","image":"ftp://example.com"}]"#
+        )
+        .is_err());
+
+        for color in ["0", "16777216"] {
+            assert!(serde_json::from_str::<EmbedPatch>(&format!(
+                r#"[{{"op":"set_color","color":{color}}}]"#
+            ))
+            .is_err());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,566 @@
+//! Render embeds for terminals with ANSI escape codes.
+
+use super::{
+    field_rows, format_timestamp,
+    markup::{self, Block, Span, Style},
+};
+use crate::as_embed::AsEmbed;
+use std::fmt::Write;
+use twilight_model::channel::embed::{Embed, EmbedField};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Color of the bar of embeds without a color.
+const DEFAULT_COLOR: u32 = 0x4f_54_5c;
+
+/// Minimum width of the rendered embed, in columns.
+const MINIMUM_WIDTH: usize = 24;
+
+/// Escape code resetting all styles.
+const RESET: &str = "\x1b[0m";
+
+/// Grapheme of rendered text with its style.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct Cell {
+    dim: bool,
+    grapheme: String,
+    link: bool,
+    style: Style,
+}
+
+impl Cell {
+    /// Cell of a grapheme of text.
+    ///
+    /// Control characters other than line breaks are replaced, so that text
+    /// can't inject escape codes into the terminal or break the layout. Tabs
+    /// are replaced with a space, and other control characters with U+FFFD.
+    fn new(grapheme: &str, style: Style, dim: bool, link: bool) -> Self {
+        let grapheme = match grapheme {
+            "\n" | "\r\n" => grapheme.to_owned(),
+            "\t" => " ".to_owned(),
+            _ => grapheme
+                .chars()
+                .map(|c| {
+                    if c.is_control() {
+                        char::REPLACEMENT_CHARACTER
+                    } else {
+                        c
+                    }
+                })
+                .collect(),
+        };
+
+        Self {
+            dim,
+            grapheme,
+            link,
+            style,
+        }
+    }
+
+    /// Unstyled space.
+    fn space() -> Self {
+        Self {
+            grapheme: " ".to_owned(),
+            ..Self::default()
+        }
+    }
+
+    /// Escape codes selecting the style of the cell.
+    fn codes(&self) -> String {
+        let mut codes = Vec::new();
+
+        if self.style.bold {
+            codes.push("1");
+        }
+
+        if self.dim {
+            codes.push("2");
+        }
+
+        if self.style.italic {
+            codes.push("3");
+        }
+
+        if self.style.underline {
+            codes.push("4");
+        }
+
+        if self.style.strikethrough {
+            codes.push("9");
+        }
+
+        if self.link {
+            codes.push("38;5;75");
+        }
+
+        if self.style.code {
+            codes.push("48;5;236");
+        }
+
+        if self.style.spoiler {
+            codes.push("38;5;240;48;5;240");
+        }
+
+        if codes.is_empty() {
+            String::new()
+        } else {
+            format!("\x1b[{}m", codes.join(";"))
+        }
+    }
+}
+
+/// Render an embed for a terminal, using ANSI escape codes for colors and
+/// styles.
+///
+/// The embed is drawn in a box of `width` columns with a side bar in the
+/// embed's color. Inline fields are arranged in rows of up to three columns,
+/// or two when there's a thumbnail, like in the Discord client. Markdown in
+/// the title, description and fields is styled, including bold, italic,
+/// underlined, struck through and spoilered text, code, links, block quotes,
+/// headers and lists.
+///
+/// Widths are measured in grapheme clusters, so text containing characters
+/// that terminals display as two columns wide, such as most emoji, isn't
+/// aligned exactly.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{render, Color, EmbedBuilder, EmbedFieldBuilder};
+///
+/// let builder = EmbedBuilder::new()
+///     .color(Color::BLURPLE)
+///     .title("Server status")
+///     .description("Everything is **operational**.")
+///     .field(EmbedFieldBuilder::new("Players", "12").inline())
+///     .field(EmbedFieldBuilder::new("Uptime", "3 days").inline());
+///
+/// println!("{}", render::ansi(&builder, 60));
+/// ```
+#[must_use = "rendering the embed has no effect if left unused"]
+pub fn ansi(embed: &impl AsEmbed, width: usize) -> String {
+    let embed = embed.as_embed();
+    let width = width.max(MINIMUM_WIDTH);
+    let inner = width - 4;
+    let mut sections = vec![header(embed, inner)];
+
+    if let Some(description) = &embed.description {
+        sections.push(markdown(description, inner));
+    }
+
+    let columns = if embed.thumbnail.is_some() { 2 } else { 3 };
+
+    for row in field_rows(&embed.fields, columns) {
+        sections.push(field_row(row, columns, inner));
+    }
+
+    if let Some(image) = &embed.image {
+        sections.push(wrap(
+            plain(&format!("Image: {}", image.url), Style::default(), true),
+            inner,
+        ));
+    }
+
+    let footer = embed.footer.as_ref().map(|footer| footer.text.clone());
+    let timestamp = embed.timestamp.map(format_timestamp);
+    let footer = match (footer, timestamp) {
+        (Some(footer), Some(timestamp)) => Some(format!("{footer} • {timestamp}")),
+        (footer, timestamp) => footer.or(timestamp),
+    };
+
+    if let Some(footer) = footer {
+        sections.push(wrap(plain(&footer, Style::default(), true), inner));
+    }
+
+    frame(sections, embed.color.unwrap_or(DEFAULT_COLOR), width)
+}
+
+/// Cells of spans of markdown.
+fn cells(spans: &[Span], dim: bool) -> Vec<Cell> {
+    spans
+        .iter()
+        .flat_map(|span| {
+            span.text
+                .graphemes(true)
+                .map(move |grapheme| Cell::new(grapheme, span.style, dim, span.link.is_some()))
+        })
+        .collect()
+}
+
+/// Lines of a row of fields, with inline fields side by side in columns.
+///
+/// Inline fields take up one of the columns, even when the row has fewer
+/// fields than columns, while other fields take up the entire width.
+fn field_row(row: &[EmbedField], columns: usize, width: usize) -> Vec<Vec<Cell>> {
+    let gap = 2;
+    let column_width = if row[0].inline {
+        (width - gap * (columns - 1)) / columns
+    } else {
+        width
+    };
+    let columns = row
+        .iter()
+        .map(|field| {
+            let mut name = cells(&markup::spans(&field.name), false);
+
+            for cell in &mut name {
+                cell.style.bold = true;
+            }
+
+            let mut lines = wrap(name, column_width);
+            lines.extend(markdown(&field.value, column_width));
+
+            lines
+        })
+        .collect::<Vec<_>>();
+    let height = columns.iter().map(Vec::len).max().unwrap_or_default();
+
+    (0..height)
+        .map(|idx| {
+            let mut line = Vec::new();
+
+            for (column_idx, column) in columns.iter().enumerate() {
+                if column_idx > 0 {
+                    line.extend((0..gap).map(|_| Cell::space()));
+                }
+
+                let cells = column.get(idx).cloned().unwrap_or_default();
+                let padding = column_width.saturating_sub(cells.len());
+                line.extend(cells);
+
+                if column_idx + 1 < columns.len() {
+                    line.extend((0..padding).map(|_| Cell::space()));
+                }
+            }
+
+            line
+        })
+        .collect()
+}
+
+/// Draw sections of lines in a box with a side bar in a color, separating the
+/// sections with blank lines.
+fn frame(sections: Vec<Vec<Vec<Cell>>>, color: u32, width: usize) -> String {
+    let inner = width - 4;
+    let bar = format!(
+        "\x1b[38;2;{};{};{}m",
+        (color >> 16) & 0xff,
+        (color >> 8) & 0xff,
+        color & 0xff
+    );
+    let border = "─".repeat(width - 2);
+    let mut output = format!("{bar}╭{RESET}{border}╮\n");
+    let sections = sections.into_iter().filter(|lines| !lines.is_empty());
+
+    for (idx, lines) in sections.enumerate() {
+        if idx > 0 {
+            let _ = writeln!(output, "{bar}▌{RESET} {} │", " ".repeat(inner));
+        }
+
+        for line in lines {
+            let padding = " ".repeat(inner.saturating_sub(line.len()));
+            let _ = writeln!(output, "{bar}▌{RESET} {}{padding} │", paint(&line));
+        }
+    }
+
+    let _ = write!(output, "{bar}╰{RESET}{border}╯");
+
+    output
+}
+
+/// Lines with the author, title and thumbnail.
+fn header(embed: &Embed, width: usize) -> Vec<Vec<Cell>> {
+    let mut lines = Vec::new();
+
+    if let Some(author) = &embed.author {
+        let bold = Style {
+            bold: true,
+            ..Style::default()
+        };
+
+        lines.extend(wrap(plain(&author.name, bold, false), width));
+    }
+
+    if let Some(title) = &embed.title {
+        let mut cells = cells(&markup::spans(title), false);
+
+        for cell in &mut cells {
+            cell.style.bold = true;
+            cell.link |= embed.url.is_some();
+        }
+
+        lines.extend(wrap(cells, width));
+    }
+
+    if let Some(thumbnail) = &embed.thumbnail {
+        let text = format!("Thumbnail: {}", thumbnail.url);
+        lines.extend(wrap(plain(&text, Style::default(), true), width));
+    }
+
+    lines
+}
+
+/// Lines of markdown wrapped to a width.
+fn markdown(text: &str, width: usize) -> Vec<Vec<Cell>> {
+    let mut lines = Vec::new();
+
+    for block in markup::blocks(text) {
+        match block {
+            Block::Code { code, .. } => {
+                let code_style = Style {
+                    code: true,
+                    ..Style::default()
+                };
+
+                for line in code.split('\n') {
+                    let cells = plain(line, code_style, false);
+
+                    if cells.is_empty() {
+                        lines.push(cells);
+                    } else {
+                        lines.extend(cells.chunks(width).map(<[Cell]>::to_vec));
+                    }
+                }
+            }
+            Block::Header { level, spans } => {
+                let mut cells = cells(&spans, false);
+
+                for cell in &mut cells {
+                    cell.style.bold = true;
+                    cell.style.underline |= level == 1;
+                }
+
+                lines.extend(wrap(cells, width));
+            }
+            Block::ListItem { marker, spans } => {
+                let marker = format!("{} ", marker.unwrap_or("•"));
+                lines.extend(prefixed(&marker, cells(&spans, false), width));
+            }
+            Block::Paragraph(spans) => lines.extend(wrap(cells(&spans, false), width)),
+            Block::Quote(spans) => {
+                let mut quoted = prefixed("▎ ", cells(&spans, false), width);
+
+                for line in &mut quoted {
+                    line[0].dim = true;
+                }
+
+                lines.extend(quoted);
+            }
+        }
+    }
+
+    lines
+}
+
+/// Render a line of cells with escape codes, resetting styles at the end.
+fn paint(line: &[Cell]) -> String {
+    let mut output = String::new();
+    let mut current = String::new();
+
+    for cell in line {
+        let codes = cell.codes();
+
+        if codes != current {
+            if !current.is_empty() {
+                output.push_str(RESET);
+            }
+
+            output.push_str(&codes);
+            current = codes;
+        }
+
+        output.push_str(&cell.grapheme);
+    }
+
+    if !current.is_empty() {
+        output.push_str(RESET);
+    }
+
+    output
+}
+
+/// Cells of text without markdown.
+fn plain(text: &str, style: Style, dim: bool) -> Vec<Cell> {
+    text.graphemes(true)
+        .map(|grapheme| Cell::new(grapheme, style, dim, false))
+        .collect()
+}
+
+/// Wrap cells to a width with a prefix on the first line, indenting the
+/// following lines by the width of the prefix.
+fn prefixed(prefix: &str, cells: Vec<Cell>, width: usize) -> Vec<Vec<Cell>> {
+    let indent = prefix.graphemes(true).count();
+    let mut lines = wrap(cells, width.saturating_sub(indent).max(1));
+
+    for (idx, line) in lines.iter_mut().enumerate() {
+        let start = if idx == 0 {
+            plain(prefix, Style::default(), false)
+        } else {
+            (0..indent).map(|_| Cell::space()).collect()
+        };
+
+        line.splice(0..0, start);
+    }
+
+    lines
+}
+
+/// Wrap cells to a width, breaking lines at spaces where possible.
+fn wrap(cells: Vec<Cell>, width: usize) -> Vec<Vec<Cell>> {
+    let mut lines = Vec::new();
+    let mut line = Vec::new();
+
+    for cell in cells {
+        if cell.grapheme == "\n" || cell.grapheme == "\r\n" {
+            lines.push(std::mem::take(&mut line));
+
+            continue;
+        }
+
+        if line.len() == width {
+            if cell.grapheme == " " {
+                lines.push(std::mem::take(&mut line));
+
+                continue;
+            }
+
+            match line.iter().rposition(|cell: &Cell| cell.grapheme == " ") {
+                Some(space) if space > 0 => {
+                    let rest = line.split_off(space + 1);
+                    line.pop();
+                    lines.push(std::mem::replace(&mut line, rest));
+                }
+                _ => lines.push(std::mem::take(&mut line)),
+            }
+        }
+
+        line.push(cell);
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ansi, paint, plain, wrap, Cell};
+    use crate::{
+        markdown::Markdown, render::markup::Style, Color, EmbedBuilder, EmbedFieldBuilder,
+    };
+
+    /// Remove escape codes from rendered output.
+    fn strip(output: &str) -> String {
+        let mut stripped = String::new();
+        let mut chars = output.chars();
+
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| *c == 'm');
+            } else {
+                stripped.push(c);
+            }
+        }
+
+        stripped
+    }
+
+    #[test]
+    fn wrapping() {
+        let text = |lines: Vec<Vec<Cell>>| {
+            lines
+                .iter()
+                .map(|line| {
+                    line.iter()
+                        .map(|cell| cell.grapheme.as_str())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+        };
+        let cells = plain("the quick brown fox\njumps", Style::default(), false);
+
+        assert_eq!(
+            ["the quick", "brown fox", "jumps"],
+            text(wrap(cells, 10)).as_slice()
+        );
+        assert_eq!(
+            ["abcd", "efgh"],
+            text(wrap(plain("abcdefgh", Style::default(), false), 4)).as_slice()
+        );
+    }
+
+    #[test]
+    fn styles() {
+        let bold = Style {
+            bold: true,
+            ..Style::default()
+        };
+
+        assert_eq!(
+            "\x1b[1mab\x1b[0mc",
+            paint(
+                &[
+                    plain("ab", bold, false),
+                    plain("c", Style::default(), false)
+                ]
+                .concat()
+            )
+        );
+    }
+
+    #[test]
+    fn control_characters() {
+        let builder = EmbedBuilder::new()
+            .title("a\tb")
+            .description("\x1b[2Jclear\rme\u{9b}");
+        let output = ansi(&builder, 30);
+
+        assert!(!output.contains("\x1b[2J"));
+        assert!(!output.contains('\r'));
+        assert!(!output.contains('\u{9b}'));
+        assert_eq!(
+            "╭────────────────────────────╮\n\
+            ▌ a b                        │\n\
+            ▌                            │\n\
+            ▌ \u{fffd}[2Jclear\u{fffd}me\u{fffd}              │\n\
+            ╰────────────────────────────╯",
+            strip(&output)
+        );
+    }
+
+    #[test]
+    fn layout() {
+        let builder = EmbedBuilder::new()
+            .color(Color::new(0x01_02_03).unwrap())
+            .title("Title")
+            .description(
+                Markdown::text("Some ")
+                    .push(Markdown::bold("bold"))
+                    .push(" text"),
+            )
+            .field(EmbedFieldBuilder::new("a", "1").inline())
+            .field(EmbedFieldBuilder::new("b", "2\n3").inline())
+            .field(EmbedFieldBuilder::new("c", "4"));
+        let output = ansi(&builder, 30);
+
+        assert!(output.starts_with("\x1b[38;2;1;2;3m╭\x1b[0m"));
+        assert!(output.contains("\x1b[1mbold\x1b[0m"));
+        assert_eq!(
+            "╭────────────────────────────╮\n\
+            ▌ Title                      │\n\
+            ▌                            │\n\
+            ▌ Some bold text             │\n\
+            ▌                            │\n\
+            ▌ a        b                 │\n\
+            ▌ 1        2                 │\n\
+            ▌          3                 │\n\
+            ▌                            │\n\
+            ▌ c                          │\n\
+            ▌ 4                          │\n\
+            ╰────────────────────────────╯",
+            strip(&output)
+        );
+    }
+}
@@ -0,0 +1,450 @@
+//! Render embeds as HTML that mimics the Discord client.
+
+use super::{
+    field_rows, format_timestamp,
+    markup::{self, Block, Span},
+};
+use crate::as_embed::AsEmbed;
+use std::fmt::Write;
+use twilight_model::channel::embed::EmbedField;
+
+/// Color of the bar of embeds without a color.
+const DEFAULT_COLOR: u32 = 0x1e_1f_22;
+
+/// Stylesheet of rendered embeds, scoped to their classes.
+const STYLESHEET: &str = "\
+.discord-embed{display:flex;max-width:520px;border-radius:4px;background:#2b2d31;\
+color:#dbdee1;font-family:'gg sans','Noto Sans','Helvetica Neue',Helvetica,Arial,sans-serif;\
+font-size:14px;line-height:1.375}\
+.discord-embed-bar{flex:none;width:4px;border-radius:4px 0 0 4px}\
+.discord-embed-grid{display:grid;grid-template-columns:auto min-content;\
+grid-template-rows:auto;padding:8px 16px 16px 12px;min-width:0}\
+.discord-embed-grid>*{grid-column:1/2;min-width:0;margin-top:8px}\
+.discord-embed-author{display:flex;align-items:center;font-size:14px;font-weight:600;color:#f2f3f5}\
+.discord-embed-author a{color:#f2f3f5}\
+.discord-embed-author-icon{width:24px;height:24px;margin-right:8px;border-radius:50%}\
+.discord-embed-title{font-size:16px;font-weight:600;color:#f2f3f5}\
+.discord-embed-title a{color:#00a8fc}\
+.discord-embed-description,.discord-embed-field-value{white-space:pre-wrap;word-wrap:break-word}\
+.discord-embed-fields{display:grid;grid-column:1/2;gap:8px}\
+.discord-embed-field-name{font-weight:600;color:#f2f3f5;margin-bottom:2px}\
+.discord-embed-thumbnail{grid-column:2/2;grid-row:1/8;margin-left:16px;max-width:80px;\
+max-height:80px;border-radius:4px;justify-self:end}\
+.discord-embed-image{grid-column:1/3;max-width:100%;max-height:300px;border-radius:4px;\
+margin-top:16px}\
+.discord-embed-footer{display:flex;align-items:center;font-size:12px;color:#b5bac1}\
+.discord-embed-footer-icon{width:20px;height:20px;margin-right:8px;border-radius:50%}\
+.discord-embed-footer-separator{margin:0 4px}\
+.discord-embed a{color:#00a8fc;text-decoration:none}\
+.discord-embed a:hover{text-decoration:underline}\
+.discord-embed code{font-family:Consolas,'Andale Mono WT','Andale Mono',monospace;\
+font-size:85%;background:#1e1f22;border-radius:3px;padding:0 .2em}\
+.discord-embed pre{margin:4px 0 0;white-space:pre-wrap}\
+.discord-embed pre code{display:block;padding:.5em;border:1px solid #1e1f22;\
+background:#2b2d31;font-size:14px}\
+.discord-embed blockquote{margin:0;padding:0 8px 0 12px;border-left:4px solid #4e5058}\
+.discord-embed h1,.discord-embed h2,.discord-embed h3{margin:8px 0 0;color:#f2f3f5}\
+.discord-embed h1{font-size:24px}.discord-embed h2{font-size:20px}\
+.discord-embed h3{font-size:16px}\
+.discord-embed ul,.discord-embed ol{margin:4px 0 0;padding-left:20px}\
+.discord-spoiler{background:#1e1f22;color:transparent;border-radius:3px}\
+.discord-spoiler:hover{background:#3a3c42;color:inherit}\
+";
+
+/// Render an embed as HTML that mimics the layout of the Discord client.
+///
+/// The output is a fragment with an embedded stylesheet, containing the
+/// color bar, the author with its icon and link, the title linking to the
+/// embed's URL, the thumbnail beside the text, inline fields in a grid of
+/// three columns, or two when there's a thumbnail, the image, and the footer
+/// with its icon and the formatted timestamp.
+///
+/// All text is HTML-escaped, and markdown in the title, description and
+/// fields is converted into HTML. Only `http` and `https` URLs are linked, and
+/// images are only shown for those URLs and attachment URLs.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{render, Color, EmbedBuilder};
+///
+/// let builder = EmbedBuilder::new()
+///     .color(Color::BLURPLE)
+///     .title("<Server status>")
+///     .description("Everything is **operational**.");
+/// let html = render::html(&builder);
+///
+/// assert!(html.contains("&lt;Server status&gt;"));
+/// assert!(html.contains("Everything is <strong>operational</strong>."));
+/// ```
+#[must_use = "rendering the embed has no effect if left unused"]
+pub fn html(embed: &impl AsEmbed) -> String {
+    let embed = embed.as_embed();
+    let color = embed.color.unwrap_or(DEFAULT_COLOR);
+    let mut output = String::new();
+
+    let _ = write!(
+        output,
+        "<style>{STYLESHEET}</style><div class=\"discord-embed\">\
+        <div class=\"discord-embed-bar\" style=\"background-color:#{color:06x}\"></div>\
+        <div class=\"discord-embed-grid\">"
+    );
+
+    if let Some(author) = &embed.author {
+        output.push_str("<div class=\"discord-embed-author\">");
+
+        if let Some(icon_url) = author.icon_url.as_deref().filter(|url| is_image(url)) {
+            image(&mut output, "discord-embed-author-icon", icon_url);
+        }
+
+        linked(&mut output, author.url.as_deref(), &escape(&author.name));
+        output.push_str("</div>");
+    }
+
+    if let Some(title) = &embed.title {
+        let mut title_spans = markup::spans(title);
+
+        // Links can't be nested, so masked links within a linked title are
+        // shown as text.
+        if embed.url.is_some() {
+            for span in &mut title_spans {
+                span.link = None;
+            }
+        }
+
+        output.push_str("<div class=\"discord-embed-title\">");
+        linked(&mut output, embed.url.as_deref(), &spans(&title_spans));
+        output.push_str("</div>");
+    }
+
+    if let Some(description) = &embed.description {
+        let _ = write!(
+            output,
+            "<div class=\"discord-embed-description\">{}</div>",
+            markdown(description)
+        );
+    }
+
+    if !embed.fields.is_empty() {
+        let columns = if embed.thumbnail.is_some() { 2 } else { 3 };
+        fields(&mut output, &embed.fields, columns);
+    }
+
+    if let Some(url) = embed.image.as_ref().map(|image| image.url.as_str()) {
+        if is_image(url) {
+            image(&mut output, "discord-embed-image", url);
+        }
+    }
+
+    if let Some(url) = embed
+        .thumbnail
+        .as_ref()
+        .map(|thumbnail| thumbnail.url.as_str())
+    {
+        if is_image(url) {
+            image(&mut output, "discord-embed-thumbnail", url);
+        }
+    }
+
+    if embed.footer.is_some() || embed.timestamp.is_some() {
+        output.push_str("<div class=\"discord-embed-footer\">");
+
+        if let Some(footer) = &embed.footer {
+            if let Some(icon_url) = footer.icon_url.as_deref().filter(|url| is_image(url)) {
+                image(&mut output, "discord-embed-footer-icon", icon_url);
+            }
+
+            let _ = write!(output, "<span>{}</span>", escape(&footer.text));
+        }
+
+        if let Some(timestamp) = embed.timestamp {
+            if embed.footer.is_some() {
+                output.push_str("<span class=\"discord-embed-footer-separator\">•</span>");
+            }
+
+            let _ = write!(
+                output,
+                "<time datetime=\"{}\">{}</time>",
+                timestamp.iso_8601(),
+                format_timestamp(timestamp)
+            );
+        }
+
+        output.push_str("</div>");
+    }
+
+    output.push_str("</div></div>");
+
+    output
+}
+
+/// Escape text for use in HTML, including in attribute values.
+pub(super) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Append the fields in a grid of twelve tracks, with inline fields sharing
+/// rows of columns.
+fn fields(output: &mut String, fields: &[EmbedField], columns: usize) {
+    output.push_str(
+        "<div class=\"discord-embed-fields\" style=\"grid-template-columns:repeat(12,1fr)\">",
+    );
+
+    let span = 12 / columns;
+
+    for (row_idx, row) in field_rows(fields, columns).into_iter().enumerate() {
+        for (column_idx, field) in row.iter().enumerate() {
+            let (start, end) = if field.inline {
+                (column_idx * span + 1, (column_idx + 1) * span + 1)
+            } else {
+                (1, 13)
+            };
+
+            let _ = write!(
+                output,
+                "<div class=\"discord-embed-field\" style=\"grid-column:{start}/{end};grid-row:{}\">\
+                <div class=\"discord-embed-field-name\">{}</div>\
+                <div class=\"discord-embed-field-value\">{}</div></div>",
+                row_idx + 1,
+                spans(&markup::spans(&field.name)),
+                markdown(&field.value)
+            );
+        }
+    }
+
+    output.push_str("</div>");
+}
+
+/// Append an image.
+fn image(output: &mut String, class: &str, url: &str) {
+    let _ = write!(
+        output,
+        "<img class=\"{class}\" src=\"{}\" alt=\"\">",
+        escape(url)
+    );
+}
+
+/// Whether a URL can be displayed as an image.
+fn is_image(url: &str) -> bool {
+    is_link(url) || url.starts_with("attachment://")
+}
+
+/// Whether a URL can be linked to.
+fn is_link(url: &str) -> bool {
+    url.starts_with("https://") || url.starts_with("http://")
+}
+
+/// Append HTML, linking it to a URL if there is one that can be linked to.
+fn linked(output: &mut String, url: Option<&str>, html: &str) {
+    match url.filter(|url| is_link(url)) {
+        Some(url) => {
+            let _ = write!(
+                output,
+                "<a href=\"{}\" target=\"_blank\" rel=\"noreferrer noopener\">{html}</a>",
+                escape(url)
+            );
+        }
+        None => output.push_str(html),
+    }
+}
+
+/// Convert markdown into HTML.
+fn markdown(text: &str) -> String {
+    let mut output = String::new();
+    let mut list: Option<bool> = None;
+
+    for block in markup::blocks(text) {
+        let ordered = match &block {
+            Block::ListItem { marker, .. } => Some(marker.is_some()),
+            _ => None,
+        };
+
+        if list.is_some() && list != ordered {
+            output.push_str(if list == Some(true) { "</ol>" } else { "</ul>" });
+            list = None;
+        }
+
+        match block {
+            Block::Code { code, language } => {
+                output.push_str("<pre><code");
+
+                if let Some(language) = language {
+                    let _ = write!(output, " class=\"language-{}\"", escape(language));
+                }
+
+                let _ = write!(output, ">{}</code></pre>", escape(code));
+            }
+            Block::Header {
+                level,
+                spans: inner,
+            } => {
+                let _ = write!(output, "<h{level}>{}</h{level}>", spans(&inner));
+            }
+            Block::ListItem {
+                marker,
+                spans: inner,
+            } => {
+                if list.is_none() {
+                    match marker.and_then(|marker| marker.trim_end_matches('.').parse::<u64>().ok())
+                    {
+                        Some(start) => {
+                            let _ = write!(output, "<ol start=\"{start}\">");
+                        }
+                        None => output.push_str("<ul>"),
+                    }
+
+                    list = ordered;
+                }
+
+                let _ = write!(output, "<li>{}</li>", spans(&inner));
+            }
+            Block::Paragraph(inner) => {
+                let _ = write!(output, "<div>{}</div>", spans(&inner));
+            }
+            Block::Quote(inner) => {
+                let _ = write!(output, "<blockquote>{}</blockquote>", spans(&inner));
+            }
+        }
+    }
+
+    match list {
+        Some(true) => output.push_str("</ol>"),
+        Some(false) => output.push_str("</ul>"),
+        None => {}
+    }
+
+    output
+}
+
+/// Convert spans of inline styles into HTML.
+fn spans(spans: &[Span]) -> String {
+    let mut output = String::new();
+
+    for span in spans {
+        let style = span.style;
+        let tags = [
+            (style.bold, "strong", "<strong>"),
+            (style.italic, "em", "<em>"),
+            (style.underline, "u", "<u>"),
+            (style.strikethrough, "s", "<s>"),
+            (style.spoiler, "span", "<span class=\"discord-spoiler\">"),
+            (style.code, "code", "<code>"),
+        ];
+        let mut inner = escape(&span.text);
+
+        for (enabled, name, open) in tags.iter().rev() {
+            if *enabled {
+                inner = format!("{open}{inner}</{name}>");
+            }
+        }
+
+        match span.link.as_deref() {
+            Some(url) => linked(&mut output, Some(url), &inner),
+            None => output.push_str(&inner),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape, html, markdown};
+    use crate::{
+        EmbedAuthorBuilder, EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder, ImageSource,
+    };
+    use std::error::Error;
+    use twilight_model::util::Timestamp;
+
+    #[test]
+    fn escaping() {
+        assert_eq!("&lt;script&gt;&amp;&quot;&#39;", escape("<script>&\"'"));
+        assert_eq!(
+            "<div>&lt;b&gt; <strong>&lt;i&gt;</strong></div>",
+            markdown("<b> **<i>**")
+        );
+    }
+
+    #[test]
+    fn markdown_blocks() {
+        assert_eq!(
+            "<h2>Header</h2><ul><li>a</li><li><em>b</em></li></ul>\
+            <ol start=\"3\"><li>c</li></ol><blockquote>quote</blockquote>\
+            <pre><code class=\"language-rs\">let a = &amp;b;</code></pre>\
+            <div>see <a href=\"https://docs.rs\" target=\"_blank\" rel=\"noreferrer noopener\">\
+            <span class=\"discord-spoiler\">docs</span></a></div>",
+            markdown(
+                "## Header\n- a\n- *b*\n3. c\n> quote\n```rs\nlet a = &b;\n```\n\
+                see [||docs||](https://docs.rs)"
+            )
+        );
+    }
+
+    #[test]
+    fn layout() -> Result<(), Box<dyn Error>> {
+        let embed = EmbedBuilder::new()
+            .author(
+                EmbedAuthorBuilder::new("**author**".to_owned())
+                    .icon_url(ImageSource::url("https://example.com/icon.png")?)
+                    .url("javascript:alert(1)"),
+            )
+            .title("Title")
+            .url("https://example.com")
+            .field(EmbedFieldBuilder::new("a", "1").inline())
+            .field(EmbedFieldBuilder::new("b", "2").inline())
+            .field(EmbedFieldBuilder::new("c", "3"))
+            .thumbnail(ImageSource::attachment("thumbnail.png")?)
+            .footer(EmbedFooterBuilder::new("<footer>"))
+            .timestamp(Timestamp::from_secs(1_627_923_403)?);
+        let output = html(&embed);
+
+        assert!(output.contains(
+            "<div class=\"discord-embed-author\"><img class=\"discord-embed-author-icon\" \
+            src=\"https://example.com/icon.png\" alt=\"\">**author**</div>"
+        ));
+        assert!(output.contains(
+            "<div class=\"discord-embed-title\"><a href=\"https://example.com\" \
+            target=\"_blank\" rel=\"noreferrer noopener\">Title</a></div>"
+        ));
+        assert!(output.contains("style=\"grid-column:1/7;grid-row:1\""));
+        assert!(output.contains("style=\"grid-column:7/13;grid-row:1\""));
+        assert!(output.contains("style=\"grid-column:1/13;grid-row:2\""));
+        assert!(output.contains("src=\"attachment://thumbnail.png\""));
+        assert!(output.contains(
+            "<span>&lt;footer&gt;</span><span class=\"discord-embed-footer-separator\">•</span>\
+            <time datetime=\"2021-08-02T16:56:43.000000+00:00\">2021-08-02 16:56 UTC</time>"
+        ));
+        assert!(!output.contains("javascript"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn title_links() {
+        let title = "[Status](https://example.com/status) page";
+        let linked = html(&EmbedBuilder::new().title(title).url("https://example.com"));
+        let unlinked = html(&EmbedBuilder::new().title(title));
+
+        assert!(linked.contains(
+            "<div class=\"discord-embed-title\"><a href=\"https://example.com\" \
+            target=\"_blank\" rel=\"noreferrer noopener\">Status page</a></div>"
+        ));
+        assert!(unlinked.contains(
+            "<div class=\"discord-embed-title\"><a href=\"https://example.com/status\" \
+            target=\"_blank\" rel=\"noreferrer noopener\">Status</a> page</div>"
+        ));
+    }
+}
@@ -0,0 +1,462 @@
+//! Parse the subset of Discord markdown that renderers display.
+
+/// Style of a span of text.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(super) struct Style {
+    pub bold: bool,
+    pub code: bool,
+    pub italic: bool,
+    pub spoiler: bool,
+    pub strikethrough: bool,
+    pub underline: bool,
+}
+
+/// Span of text with a single style, possibly linking to a URL.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct Span {
+    pub link: Option<String>,
+    pub style: Style,
+    pub text: String,
+}
+
+/// Block of text, which starts on its own line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum Block<'a> {
+    /// Fenced code block, with the language it's highlighted as.
+    Code {
+        code: &'a str,
+        language: Option<&'a str>,
+    },
+    /// Header with a level from 1 to 3.
+    Header { level: usize, spans: Vec<Span> },
+    /// Item of a list, with the marker of ordered lists.
+    ListItem {
+        marker: Option<&'a str>,
+        spans: Vec<Span>,
+    },
+    /// Lines of text.
+    Paragraph(Vec<Span>),
+    /// Quoted lines of text.
+    Quote(Vec<Span>),
+}
+
+/// Delimiters of inline styles, in the order that they're matched.
+const DELIMITERS: [&str; 6] = ["**", "__", "~~", "||", "*", "_"];
+
+/// Parse text into blocks.
+pub(super) fn blocks(text: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Option<(usize, usize)> = None;
+    let mut quote = String::new();
+    let mut offset = 0;
+
+    let flush = |blocks: &mut Vec<Block<'_>>, paragraph: &mut Option<(usize, usize)>| {
+        if let Some((start, end)) = paragraph.take() {
+            blocks.push(Block::Paragraph(spans(&text[start..end])));
+        }
+    };
+
+    while offset < text.len() {
+        let rest = &text[offset..];
+        let line_end = rest.find('\n').map_or(rest.len(), |idx| idx);
+        let line = &rest[..line_end];
+        let next = (offset + line_end + 1).min(text.len());
+
+        if let Some(fenced) = rest.strip_prefix("```") {
+            if let Some(close) = fenced.find("```") {
+                flush(&mut blocks, &mut paragraph);
+                blocks.push(code_block(&fenced[..close]));
+
+                let after = offset + 3 + close + 3;
+                offset = if text[after..].starts_with('\n') {
+                    after + 1
+                } else {
+                    after
+                };
+
+                continue;
+            }
+        }
+
+        if let Some(quoted) = line.strip_prefix(">>> ") {
+            flush(&mut blocks, &mut paragraph);
+            let start = offset + (line.len() - quoted.len());
+            quote.push_str(&text[start..]);
+            blocks.push(Block::Quote(spans(&quote)));
+
+            break;
+        }
+
+        if let Some(quoted) = line
+            .strip_prefix("> ")
+            .or_else(|| (line == ">").then(|| ""))
+        {
+            flush(&mut blocks, &mut paragraph);
+
+            if !quote.is_empty() {
+                quote.push('\n');
+            }
+
+            quote.push_str(quoted);
+            offset = next;
+
+            if !text[offset..].starts_with('>') || text[offset..].starts_with(">>> ") {
+                blocks.push(Block::Quote(spans(&quote)));
+                quote.clear();
+            }
+
+            continue;
+        }
+
+        if let Some((level, header)) = header(line) {
+            flush(&mut blocks, &mut paragraph);
+            blocks.push(Block::Header {
+                level,
+                spans: spans(header),
+            });
+        } else if let Some((marker, item)) = list_item(line) {
+            flush(&mut blocks, &mut paragraph);
+            blocks.push(Block::ListItem {
+                marker,
+                spans: spans(item),
+            });
+        } else {
+            let end = offset + line_end;
+            paragraph = Some(paragraph.map_or((offset, end), |(start, _)| (start, end)));
+        }
+
+        offset = next;
+    }
+
+    flush(&mut blocks, &mut paragraph);
+
+    blocks
+}
+
+/// Parse text into spans of inline styles.
+pub(super) fn spans(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    parse_inline(text, Style::default(), None, &mut spans);
+
+    spans
+}
+
+/// Parse the contents of a fenced code block, separating the language on the
+/// first line from the code.
+fn code_block(contents: &str) -> Block<'_> {
+    if let Some((first, code)) = contents.split_once('\n') {
+        let language = first.trim();
+
+        if !language.is_empty() && !language.contains(char::is_whitespace) {
+            return Block::Code {
+                code: code.strip_suffix('\n').unwrap_or(code),
+                language: Some(language),
+            };
+        }
+
+        if language.is_empty() {
+            return Block::Code {
+                code: code.strip_suffix('\n').unwrap_or(code),
+                language: None,
+            };
+        }
+    }
+
+    Block::Code {
+        code: contents.trim_matches('\n'),
+        language: None,
+    }
+}
+
+/// Level and text of a header line.
+fn header(line: &str) -> Option<(usize, &str)> {
+    ["# ", "## ", "### "]
+        .iter()
+        .enumerate()
+        .find_map(|(idx, prefix)| line.strip_prefix(prefix).map(|rest| (idx + 1, rest)))
+}
+
+/// Marker of ordered lists and text of a list item line.
+fn list_item(line: &str) -> Option<(Option<&str>, &str)> {
+    let line = line.trim_start();
+
+    if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some((None, item));
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+
+    if digits > 0 && line[digits..].starts_with(". ") {
+        return Some((Some(&line[..=digits]), &line[digits + 2..]));
+    }
+
+    None
+}
+
+/// Parse inline styles, appending spans with the base style and link.
+fn parse_inline(text: &str, style: Style, link: Option<&str>, spans: &mut Vec<Span>) {
+    let mut literal = String::new();
+    let mut idx = 0;
+
+    while idx < text.len() {
+        let rest = &text[idx..];
+
+        if let Some(escaped) = rest.strip_prefix('\\') {
+            if let Some(c) = escaped.chars().next().filter(char::is_ascii_punctuation) {
+                literal.push(c);
+                idx += 1 + c.len_utf8();
+
+                continue;
+            }
+        }
+
+        if let Some((code, length)) = inline_code(rest) {
+            push(spans, &mut literal, style, link);
+            spans.push(Span {
+                link: link.map(ToOwned::to_owned),
+                style: Style {
+                    code: true,
+                    ..style
+                },
+                text: code.to_owned(),
+            });
+            idx += length;
+
+            continue;
+        }
+
+        if link.is_none() {
+            if let Some((label, url, length)) = masked_link(rest) {
+                push(spans, &mut literal, style, link);
+                parse_inline(label, style, Some(url), spans);
+                idx += length;
+
+                continue;
+            }
+        }
+
+        let previous = text[..idx].chars().next_back();
+
+        if let Some((delimiter, inner)) = DELIMITERS.iter().find_map(|delimiter| {
+            delimited(rest, delimiter, previous).map(|inner| (*delimiter, inner))
+        }) {
+            push(spans, &mut literal, style, link);
+            parse_inline(inner, styled(style, delimiter), link, spans);
+            idx += inner.len() + delimiter.len() * 2;
+
+            continue;
+        }
+
+        let c = rest.chars().next().unwrap_or_default();
+        literal.push(c);
+        idx += c.len_utf8();
+    }
+
+    push(spans, &mut literal, style, link);
+}
+
+/// Push literal text as a span, if there is any.
+fn push(spans: &mut Vec<Span>, literal: &mut String, style: Style, link: Option<&str>) {
+    if literal.is_empty() {
+        return;
+    }
+
+    match spans.last_mut() {
+        Some(last) if last.style == style && last.link.as_deref() == link => {
+            last.text.push_str(literal);
+        }
+        _ => spans.push(Span {
+            link: link.map(ToOwned::to_owned),
+            style,
+            text: literal.clone(),
+        }),
+    }
+
+    literal.clear();
+}
+
+/// Style with the style of a delimiter applied.
+const fn styled(mut style: Style, delimiter: &str) -> Style {
+    match delimiter.as_bytes() {
+        b"**" => style.bold = true,
+        b"__" => style.underline = true,
+        b"~~" => style.strikethrough = true,
+        b"||" => style.spoiler = true,
+        _ => style.italic = true,
+    }
+
+    style
+}
+
+/// Text within a delimiter at the start of text.
+///
+/// The closing delimiter is extended to the end of a run of the delimiter's
+/// character, so that `***text***` is bold and italic. Single delimiters
+/// can't be followed by whitespace, and underscores must be at word
+/// boundaries.
+fn delimited<'a>(text: &'a str, delimiter: &str, previous: Option<char>) -> Option<&'a str> {
+    let rest = text.strip_prefix(delimiter)?;
+    let single = delimiter.len() == 1;
+
+    if single && rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    if delimiter == "_" && previous.map_or(false, char::is_alphanumeric) {
+        return None;
+    }
+
+    let character = delimiter.chars().next()?;
+    let mut search = 0;
+
+    while let Some(found) = rest[search..].find(delimiter) {
+        let mut close = search + found;
+
+        while rest[close + delimiter.len()..].starts_with(character) {
+            close += character.len_utf8();
+        }
+
+        let inner = &rest[..close];
+        let after = rest[close + delimiter.len()..].chars().next();
+        let boundary = delimiter != "_" || !after.map_or(false, char::is_alphanumeric);
+
+        let padded = single && inner.ends_with(char::is_whitespace);
+
+        if !inner.is_empty() && !padded && boundary {
+            return Some(inner);
+        }
+
+        search = close + delimiter.len();
+    }
+
+    None
+}
+
+/// Code and total length of inline code at the start of text.
+fn inline_code(text: &str) -> Option<(&str, usize)> {
+    if text.starts_with("```") {
+        return None;
+    }
+
+    for delimiter in ["``", "`"] {
+        if let Some(rest) = text.strip_prefix(delimiter) {
+            if let Some(close) = rest.find(delimiter).filter(|close| *close > 0) {
+                let code = &rest[..close];
+                let code = if delimiter.len() == 2 {
+                    code.strip_prefix(' ')
+                        .and_then(|code| code.strip_suffix(' '))
+                        .unwrap_or(code)
+                } else {
+                    code
+                };
+
+                return Some((code, close + delimiter.len() * 2));
+            }
+        }
+    }
+
+    None
+}
+
+/// Label, URL and total length of a masked link at the start of text.
+fn masked_link(text: &str) -> Option<(&str, &str, usize)> {
+    let rest = text.strip_prefix('[')?;
+    let label_end = rest.find("](")?;
+    let label = &rest[..label_end];
+
+    if label.contains('\n') {
+        return None;
+    }
+
+    let target = &rest[label_end + 2..];
+    let url_end = target.find(')')?;
+    let url = target[..url_end]
+        .trim_start_matches('<')
+        .trim_end_matches('>');
+
+    if !url.starts_with("https://") && !url.starts_with("http://") {
+        return None;
+    }
+
+    Some((label, url, 1 + label_end + 2 + url_end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blocks, spans, Block, Span, Style};
+
+    fn texts(spans: &[Span]) -> Vec<(&str, Style)> {
+        spans
+            .iter()
+            .map(|span| (span.text.as_str(), span.style))
+            .collect()
+    }
+
+    #[test]
+    fn inline() {
+        let bold = Style {
+            bold: true,
+            ..Style::default()
+        };
+        let both = Style {
+            italic: true,
+            ..bold
+        };
+        let code = Style {
+            code: true,
+            ..Style::default()
+        };
+
+        assert_eq!(
+            [
+                ("a ", Style::default()),
+                ("b", bold),
+                (" ", Style::default()),
+                ("c", both),
+                (" ", Style::default()),
+                ("*d*", code),
+                (" snake_case_name * e", Style::default()),
+            ],
+            texts(&spans("a **b** ***c*** `*d*` snake_case_name * e")).as_slice()
+        );
+        assert_eq!(
+            [("**not bold**", Style::default())],
+            texts(&spans(r"\*\*not bold\*\*")).as_slice()
+        );
+
+        let link = spans("see [the **docs**](https://docs.rs)");
+        assert_eq!(None, link[0].link);
+        assert_eq!(Some("https://docs.rs"), link[1].link.as_deref());
+        assert_eq!("docs", link[2].text);
+        assert!(link[2].style.bold);
+    }
+
+    #[test]
+    fn block_structure() {
+        let parsed = blocks(
+            "# Title\ntext\nmore\n> quote\n> lines\n- item\n2. second\n```rs\nlet a;\n```\nend",
+        );
+
+        assert!(matches!(&parsed[0], Block::Header { level: 1, .. }));
+        assert!(matches!(&parsed[1], Block::Paragraph(spans) if spans[0].text == "text\nmore"));
+        assert!(matches!(&parsed[2], Block::Quote(spans) if spans[0].text == "quote\nlines"));
+        assert!(matches!(&parsed[3], Block::ListItem { marker: None, .. }));
+        assert!(matches!(
+            &parsed[4],
+            Block::ListItem {
+                marker: Some("2."),
+                ..
+            }
+        ));
+        assert!(matches!(
+            &parsed[5],
+            Block::Code {
+                code: "let a;",
+                language: Some("rs")
+            }
+        ));
+        assert!(matches!(&parsed[6], Block::Paragraph(spans) if spans[0].text == "end"));
+        assert_eq!(7, parsed.len());
+    }
+}
@@ -0,0 +1,115 @@
+//! Render embeds for places that can't display them.
+//!
+//! Renderers accept both built [`Embed`]s and [`EmbedBuilder`]s.
+//!
+//! [`Embed`]: twilight_model::channel::embed::Embed
+//! [`EmbedBuilder`]: crate::EmbedBuilder
+
+mod ansi;
+mod html;
+mod markup;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+mod text;
+
+pub use self::{ansi::ansi, html::html, text::text};
+
+#[cfg(feature = "snapshot-png")]
+pub use self::snapshot::png;
+#[cfg(feature = "snapshot")]
+pub use self::snapshot::{svg, Theme};
+
+use twilight_model::{channel::embed::EmbedField, util::Timestamp};
+
+/// The maximum number of characters in the content of a message.
+pub const CONTENT_LENGTH_LIMIT: usize = 2000;
+
+/// Group fields into the rows that Discord displays them in.
+///
+/// Consecutive inline fields share a row of up to `columns` fields, while
+/// fields that aren't inline are on a row of their own.
+fn field_rows(fields: &[EmbedField], columns: usize) -> Vec<&[EmbedField]> {
+    let mut rows = Vec::new();
+    let mut start = 0;
+
+    while start < fields.len() {
+        let inline = fields[start..]
+            .iter()
+            .take(columns)
+            .take_while(|field| field.inline)
+            .count();
+        let end = start + inline.max(1);
+
+        rows.push(&fields[start..end]);
+        start = end;
+    }
+
+    rows
+}
+
+/// Format a timestamp as a UTC date and time, such as `2021-08-02 16:56 UTC`.
+fn format_timestamp(timestamp: Timestamp) -> String {
+    let seconds = timestamp.as_secs();
+    let (days, seconds) = (seconds.div_euclid(86_400), seconds.rem_euclid(86_400));
+
+    // Convert days since the Unix epoch into a civil date, counting eras of
+    // 400 years from the 1st of March in year 0.
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02} UTC",
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{field_rows, format_timestamp};
+    use twilight_model::{channel::embed::EmbedField, util::Timestamp};
+
+    #[test]
+    fn rows() {
+        let field = |inline| EmbedField {
+            inline,
+            name: "name".to_owned(),
+            value: "value".to_owned(),
+        };
+        let fields = [
+            field(true),
+            field(true),
+            field(true),
+            field(true),
+            field(false),
+            field(true),
+        ];
+        let lengths = field_rows(&fields, 3)
+            .into_iter()
+            .map(<[_]>::len)
+            .collect::<Vec<_>>();
+
+        assert_eq!([3, 1, 1, 1], lengths.as_slice());
+    }
+
+    #[test]
+    fn timestamp() {
+        let format = |secs| format_timestamp(Timestamp::from_secs(secs).unwrap());
+
+        assert_eq!("1970-01-01 00:00 UTC", format(0));
+        assert_eq!("2021-08-02 16:56 UTC", format(1_627_923_403));
+        assert_eq!("2000-02-29 23:59 UTC", format(951_868_799));
+    }
+}
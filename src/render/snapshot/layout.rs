@@ -0,0 +1,750 @@
+//! Lay out embeds into scenes of rectangles and text.
+
+use super::{Palette, Theme};
+use crate::render::{
+    field_rows, format_timestamp,
+    markup::{self, Block, Span, Style},
+};
+use ab_glyph::{Font, FontRef, PxScale, ScaleFont};
+use twilight_model::channel::embed::{Embed, EmbedAuthor, EmbedField, EmbedFooter};
+
+/// Width of the bar on the left of the embed.
+const BAR_WIDTH: f32 = 4.0;
+
+/// Width of embeds.
+const EMBED_WIDTH: f32 = 520.0;
+
+/// Gap between inline fields and between rows of fields.
+const FIELD_GAP: f32 = 8.0;
+
+/// Space around the embed.
+const MARGIN: f32 = 16.0;
+
+/// Space between the bar and the content of the embed.
+const PADDING_LEFT: f32 = 12.0;
+
+/// Space between the content and the right of the embed.
+const PADDING_RIGHT: f32 = 16.0;
+
+/// Space between the top of the embed and its first section.
+const PADDING_TOP: f32 = 8.0;
+
+/// Space above each section of the embed.
+const SECTION_GAP: f32 = 8.0;
+
+/// Width and height of the thumbnail.
+const THUMBNAIL_SIZE: f32 = 80.0;
+
+/// Face of the bundled font.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Face {
+    Bold,
+    Regular,
+}
+
+/// Shape or text drawn in a scene.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Item {
+    /// Rectangle, with rounded corners if the radius is positive.
+    Rect {
+        color: u32,
+        height: f32,
+        radius: f32,
+        width: f32,
+        x: f32,
+        y: f32,
+    },
+    /// Line of text, positioned at the start of its baseline.
+    Text {
+        color: u32,
+        face: Face,
+        italic: bool,
+        size: f32,
+        text: String,
+        x: f32,
+        y: f32,
+    },
+}
+
+/// Laid out embed, drawn from the first to the last item.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Scene {
+    pub height: f32,
+    pub items: Vec<Item>,
+    pub width: f32,
+}
+
+/// Faces of the bundled font.
+pub(super) struct Fonts {
+    bold: FontRef<'static>,
+    regular: FontRef<'static>,
+}
+
+impl Fonts {
+    /// Load the bundled fonts.
+    pub fn load() -> Self {
+        // The bundled fonts are known to be valid, so parsing them can't fail.
+        let parse = |data| FontRef::try_from_slice(data).expect("bundled font is valid");
+
+        Self {
+            bold: parse(include_bytes!("../../../assets/fonts/DejaVuSans-Bold.ttf")),
+            regular: parse(include_bytes!("../../../assets/fonts/DejaVuSans.ttf")),
+        }
+    }
+
+    /// Font of a face.
+    pub const fn face(&self, face: Face) -> &FontRef<'static> {
+        match face {
+            Face::Bold => &self.bold,
+            Face::Regular => &self.regular,
+        }
+    }
+
+    /// Scale of a face at a font size in pixels, as used by CSS.
+    pub fn scale(&self, face: Face, size: f32) -> PxScale {
+        let font = self.face(face);
+        let units_per_em = font.units_per_em().unwrap_or(2048.0);
+
+        PxScale::from(size * font.height_unscaled() / units_per_em)
+    }
+
+    /// Distance from the top of a line to its baseline.
+    fn baseline(&self, face: Face, size: f32, line_height: f32) -> f32 {
+        let font = self.face(face).as_scaled(self.scale(face, size));
+
+        (line_height - font.height()) / 2.0 + font.ascent()
+    }
+
+    /// Width of a line of text.
+    fn width(&self, face: Face, size: f32, text: &str) -> f32 {
+        let font = self.face(face).as_scaled(self.scale(face, size));
+        let mut previous = None;
+        let mut width = 0.0;
+
+        for c in text.chars() {
+            let id = font.glyph_id(c);
+
+            if let Some(previous) = previous {
+                width += font.kern(previous, id);
+            }
+
+            width += font.h_advance(id);
+            previous = Some(id);
+        }
+
+        width
+    }
+}
+
+/// Style of a paragraph of text.
+#[derive(Clone, Copy, Debug)]
+struct TextStyle {
+    color: u32,
+    face: Face,
+    line_height: f32,
+    size: f32,
+}
+
+/// Part of a line of text with a single style.
+#[derive(Clone, Debug)]
+struct Fragment {
+    link: bool,
+    style: Style,
+    text: String,
+    width: f32,
+    x: f32,
+}
+
+/// State of laying out an embed.
+struct Layout<'a> {
+    fonts: &'a Fonts,
+    items: Vec<Item>,
+    palette: Palette,
+}
+
+/// Lay out an embed into a scene.
+pub(super) fn layout(embed: &Embed, theme: Theme, fonts: &Fonts) -> Scene {
+    let palette = theme.palette();
+    let mut layout = Layout {
+        fonts,
+        items: Vec::new(),
+        palette,
+    };
+
+    let x = MARGIN + BAR_WIDTH + PADDING_LEFT;
+    let full_width = EMBED_WIDTH - BAR_WIDTH - PADDING_LEFT - PADDING_RIGHT;
+    let width = if embed.thumbnail.is_some() {
+        full_width - THUMBNAIL_SIZE - 16.0
+    } else {
+        full_width
+    };
+    let mut y = MARGIN + PADDING_TOP;
+
+    y += layout.header(embed, x, y, width);
+
+    if let Some(description) = &embed.description {
+        y += SECTION_GAP;
+        y += layout.markdown(description, x, y, width);
+    }
+
+    if !embed.fields.is_empty() {
+        y += layout.fields(&embed.fields, embed.thumbnail.is_some(), x, y, width);
+    }
+
+    if embed.thumbnail.is_some() {
+        let top = MARGIN + PADDING_TOP + SECTION_GAP;
+        layout.rect(
+            (x + width + 16.0, top, THUMBNAIL_SIZE, THUMBNAIL_SIZE),
+            4.0,
+            palette.placeholder,
+        );
+        y = y.max(top + THUMBNAIL_SIZE);
+    }
+
+    if embed.image.is_some() {
+        let height = (full_width * 9.0 / 16.0).min(300.0);
+        y += 2.0 * SECTION_GAP;
+        layout.rect((x, y, full_width, height), 4.0, palette.placeholder);
+        y += height;
+    }
+
+    if embed.footer.is_some() || embed.timestamp.is_some() {
+        y += SECTION_GAP;
+        y += layout.footer(embed.footer.as_ref(), embed, x, y, width);
+    }
+
+    // Round up so that images are whole pixels high.
+    y = (y + 16.0).ceil();
+
+    let height = y + MARGIN;
+    let embed_height = y - MARGIN;
+    let background = [
+        Item::Rect {
+            color: palette.background,
+            height,
+            radius: 0.0,
+            width: EMBED_WIDTH + 2.0 * MARGIN,
+            x: 0.0,
+            y: 0.0,
+        },
+        Item::Rect {
+            color: embed.color.unwrap_or(palette.bar),
+            height: embed_height,
+            radius: 4.0,
+            width: EMBED_WIDTH,
+            x: MARGIN,
+            y: MARGIN,
+        },
+        Item::Rect {
+            color: palette.embed,
+            height: embed_height,
+            radius: 4.0,
+            width: EMBED_WIDTH - BAR_WIDTH,
+            x: MARGIN + BAR_WIDTH,
+            y: MARGIN,
+        },
+    ];
+    layout.items.splice(0..0, background);
+
+    Scene {
+        height,
+        items: layout.items,
+        width: EMBED_WIDTH + 2.0 * MARGIN,
+    }
+}
+
+impl Layout<'_> {
+    /// Lay out the author, with its icon.
+    fn author(&mut self, author: &EmbedAuthor, x: f32, y: f32, width: f32) -> f32 {
+        let mut indent = 0.0;
+
+        if author.icon_url.is_some() {
+            self.rect((x, y, 24.0, 24.0), 12.0, self.palette.placeholder);
+            indent = 32.0;
+        }
+
+        let style = TextStyle {
+            color: self.palette.header,
+            face: Face::Bold,
+            line_height: 24.0,
+            size: 14.0,
+        };
+
+        self.spans(&[plain(&author.name)], style, x + indent, y, width - indent)
+    }
+
+    /// Lay out a code block.
+    fn code_block(&mut self, code: &str, x: f32, y: f32, width: f32) -> f32 {
+        let index = self.items.len();
+        let style = TextStyle {
+            color: self.palette.text,
+            face: Face::Regular,
+            line_height: 16.0,
+            size: 12.0,
+        };
+        let height = self.spans(
+            &[plain(code.trim_end_matches('\n'))],
+            style,
+            x + 8.0,
+            y + 8.0,
+            width - 16.0,
+        ) + 16.0;
+
+        self.items.insert(
+            index,
+            Item::Rect {
+                color: self.palette.code,
+                height,
+                radius: 4.0,
+                width,
+                x,
+                y,
+            },
+        );
+
+        height
+    }
+
+    /// Lay out the fields in rows, returning the height of the rows.
+    fn fields(
+        &mut self,
+        fields: &[EmbedField],
+        thumbnail: bool,
+        x: f32,
+        y: f32,
+        width: f32,
+    ) -> f32 {
+        let (columns, column_width) = if thumbnail {
+            (2, (width - FIELD_GAP) / 2.0)
+        } else {
+            (3, (width - 2.0 * FIELD_GAP) / 3.0)
+        };
+        let name = TextStyle {
+            color: self.palette.header,
+            face: Face::Bold,
+            line_height: 18.0,
+            size: 14.0,
+        };
+        let mut height = 0.0;
+
+        for row in field_rows(fields, columns) {
+            let top = y + height + FIELD_GAP;
+            let mut field_x = x;
+            let mut row_height: f32 = 0.0;
+
+            for field in row {
+                let field_width = if field.inline { column_width } else { width };
+                let name_height =
+                    self.spans(&markup::spans(&field.name), name, field_x, top, field_width);
+                let value_height =
+                    self.markdown(&field.value, field_x, top + name_height + 2.0, field_width);
+
+                row_height = row_height.max(name_height + 2.0 + value_height);
+                field_x += column_width + FIELD_GAP;
+            }
+
+            height += FIELD_GAP + row_height;
+        }
+
+        height
+    }
+
+    /// Lay out the footer, with its icon, and the timestamp.
+    fn footer(
+        &mut self,
+        footer: Option<&EmbedFooter>,
+        embed: &Embed,
+        x: f32,
+        y: f32,
+        width: f32,
+    ) -> f32 {
+        let mut indent = 0.0;
+
+        if footer.map_or(false, |footer| footer.icon_url.is_some()) {
+            self.rect((x, y, 20.0, 20.0), 10.0, self.palette.placeholder);
+            indent = 28.0;
+        }
+
+        let text = footer.map(|footer| footer.text.clone());
+        let timestamp = embed.timestamp.map(format_timestamp);
+        let text = match (text, timestamp) {
+            (Some(text), Some(timestamp)) => format!("{text} • {timestamp}"),
+            (text, timestamp) => text.or(timestamp).unwrap_or_default(),
+        };
+        let style = TextStyle {
+            color: self.palette.muted,
+            face: Face::Regular,
+            line_height: 20.0,
+            size: 12.0,
+        };
+
+        self.spans(&[plain(&text)], style, x + indent, y, width - indent)
+    }
+
+    /// Lay out the author and title, returning their height.
+    fn header(&mut self, embed: &Embed, x: f32, y: f32, width: f32) -> f32 {
+        let mut height = 0.0;
+
+        if let Some(author) = &embed.author {
+            height += SECTION_GAP + self.author(author, x, y + SECTION_GAP, width);
+        }
+
+        if let Some(title) = &embed.title {
+            let style = TextStyle {
+                color: if embed.url.is_some() {
+                    self.palette.link
+                } else {
+                    self.palette.header
+                },
+                face: Face::Bold,
+                line_height: 22.0,
+                size: 16.0,
+            };
+            let top = y + height + SECTION_GAP;
+            height += SECTION_GAP + self.spans(&markup::spans(title), style, x, top, width);
+        }
+
+        height
+    }
+
+    /// Lay out markdown, returning its height.
+    fn markdown(&mut self, text: &str, x: f32, y: f32, width: f32) -> f32 {
+        let style = TextStyle {
+            color: self.palette.text,
+            face: Face::Regular,
+            line_height: 18.0,
+            size: 14.0,
+        };
+        let mut height = 0.0;
+
+        for block in markup::blocks(text) {
+            let top = y + height;
+
+            height += match block {
+                Block::Code { code, .. } => 4.0 + self.code_block(code, x, top + 4.0, width),
+                Block::Header { level, spans } => {
+                    let size = match level {
+                        1 => 20.0,
+                        2 => 18.0,
+                        _ => 16.0,
+                    };
+                    let header = TextStyle {
+                        color: self.palette.header,
+                        face: Face::Bold,
+                        line_height: size * 1.25,
+                        size,
+                    };
+
+                    4.0 + self.spans(&spans, header, x, top + 4.0, width)
+                }
+                Block::ListItem { marker, spans } => {
+                    self.spans(&[plain(marker.unwrap_or("•"))], style, x, top, 20.0);
+
+                    self.spans(&spans, style, x + 20.0, top, width - 20.0)
+                }
+                Block::Paragraph(spans) => self.spans(&spans, style, x, top, width),
+                Block::Quote(spans) => {
+                    let quote_height = self.spans(&spans, style, x + 16.0, top, width - 16.0);
+                    self.rect((x, top, 4.0, quote_height), 2.0, self.palette.quote);
+
+                    quote_height
+                }
+            };
+        }
+
+        height
+    }
+
+    /// Add a rectangle, given as its position and size.
+    fn rect(&mut self, (x, y, width, height): (f32, f32, f32, f32), radius: f32, color: u32) {
+        self.items.push(Item::Rect {
+            color,
+            height,
+            radius,
+            width,
+            x,
+            y,
+        });
+    }
+
+    /// Lay out spans of text wrapped to a width, returning their height.
+    fn spans(&mut self, spans: &[Span], style: TextStyle, x: f32, y: f32, width: f32) -> f32 {
+        let lines = self.wrap(spans, style, width);
+        let mut top = y;
+
+        for line in lines {
+            for fragment in line {
+                self.fragment(fragment, style, x, top);
+            }
+
+            top += style.line_height;
+        }
+
+        top - y
+    }
+
+    /// Add a fragment of a line, with its decorations.
+    fn fragment(&mut self, fragment: Fragment, style: TextStyle, x: f32, top: f32) {
+        let face = if fragment.style.bold {
+            Face::Bold
+        } else {
+            style.face
+        };
+        let baseline = top + self.fonts.baseline(face, style.size, style.line_height);
+        let x = x + fragment.x;
+
+        if fragment.style.code {
+            self.rect(
+                (
+                    x - 1.0,
+                    top + 1.0,
+                    fragment.width + 2.0,
+                    style.line_height - 2.0,
+                ),
+                3.0,
+                self.palette.code,
+            );
+        }
+
+        if fragment.style.spoiler {
+            self.rect(
+                (x, top + 1.0, fragment.width, style.line_height - 2.0),
+                3.0,
+                self.palette.placeholder,
+            );
+
+            return;
+        }
+
+        let color = if fragment.link {
+            self.palette.link
+        } else {
+            style.color
+        };
+
+        if fragment.style.underline {
+            self.rect((x, baseline + 2.0, fragment.width, 1.0), 0.0, color);
+        }
+
+        if fragment.style.strikethrough {
+            let y = baseline - style.size * 0.3;
+            self.rect((x, y, fragment.width, 1.0), 0.0, color);
+        }
+
+        self.items.push(Item::Text {
+            color,
+            face,
+            italic: fragment.style.italic,
+            size: style.size,
+            text: fragment.text,
+            x,
+            y: baseline,
+        });
+    }
+
+    /// Wrap spans into lines of fragments no wider than a width, unless a
+    /// single character is wider.
+    fn wrap(&self, spans: &[Span], style: TextStyle, width: f32) -> Vec<Vec<Fragment>> {
+        let mut lines = vec![Vec::new()];
+        let mut cursor = 0.0;
+
+        for span in spans {
+            let face = if span.style.bold {
+                Face::Bold
+            } else {
+                style.face
+            };
+            let measure = |text: &str| self.fonts.width(face, style.size, text);
+
+            for piece in pieces(&span.text) {
+                if piece == "\n" {
+                    self.break_line(&mut lines, &mut cursor, style);
+
+                    continue;
+                }
+
+                let space = piece.starts_with(char::is_whitespace);
+                let piece_width = measure(piece);
+
+                let characters: Vec<&str> = if !space && piece_width > width {
+                    piece
+                        .char_indices()
+                        .map(|(idx, c)| &piece[idx..idx + c.len_utf8()])
+                        .collect()
+                } else {
+                    vec![piece]
+                };
+
+                for text in characters {
+                    let text_width = measure(text);
+                    let line_empty = lines.last().map_or(true, Vec::is_empty);
+
+                    if cursor + text_width > width && !line_empty {
+                        self.break_line(&mut lines, &mut cursor, style);
+                    }
+
+                    let line = lines.last_mut().expect("there is always a line");
+
+                    if space && line.is_empty() {
+                        continue;
+                    }
+
+                    push(line, text, text_width, span, &mut cursor);
+                }
+            }
+        }
+
+        lines
+    }
+
+    /// Start a new line, removing trailing whitespace from the current line.
+    fn break_line(&self, lines: &mut Vec<Vec<Fragment>>, cursor: &mut f32, style: TextStyle) {
+        if let Some(last) = lines.last_mut().and_then(|line| line.last_mut()) {
+            let trimmed = last.text.trim_end().len();
+
+            if trimmed < last.text.len() {
+                let face = if last.style.bold {
+                    Face::Bold
+                } else {
+                    style.face
+                };
+                last.text.truncate(trimmed);
+                last.width = self.fonts.width(face, style.size, &last.text);
+            }
+        }
+
+        lines.push(Vec::new());
+        *cursor = 0.0;
+    }
+}
+
+/// Split text into words, runs of whitespace and newlines.
+fn pieces(text: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut kind = None;
+
+    for (idx, c) in text.char_indices() {
+        let current = if c == '\n' {
+            0
+        } else if c.is_whitespace() {
+            1
+        } else {
+            2
+        };
+
+        if kind != Some(current) || current == 0 {
+            if idx > start {
+                pieces.push(&text[start..idx]);
+            }
+
+            start = idx;
+            kind = Some(current);
+        }
+    }
+
+    if start < text.len() {
+        pieces.push(&text[start..]);
+    }
+
+    pieces
+}
+
+/// Unstyled span of text.
+fn plain(text: &str) -> Span {
+    Span {
+        link: None,
+        style: Style::default(),
+        text: text.to_owned(),
+    }
+}
+
+/// Append text to a line, extending its last fragment if it has the same
+/// style.
+fn push(line: &mut Vec<Fragment>, text: &str, width: f32, span: &Span, cursor: &mut f32) {
+    let text: String = text.chars().filter(|c| !c.is_control()).collect();
+    let linked = span.link.is_some();
+
+    match line.last_mut() {
+        Some(last) if last.link == linked && last.style == span.style => {
+            last.text.push_str(&text);
+            last.width += width;
+        }
+        _ => line.push(Fragment {
+            link: linked,
+            style: span.style,
+            text,
+            width,
+            x: *cursor,
+        }),
+    }
+
+    *cursor += width;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{layout, pieces, Fonts, Item};
+    use crate::{render::Theme, EmbedBuilder, EmbedFieldBuilder, ImageSource};
+    use std::error::Error;
+
+    fn texts(items: &[Item]) -> Vec<(&str, f32, f32)> {
+        items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Text { text, x, y, .. } => Some((text.as_str(), *x, *y)),
+                Item::Rect { .. } => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn splits_pieces() {
+        assert_eq!(vec!["a", "  ", "bc", "\n", "\n", "d"], pieces("a  bc\n\nd"));
+    }
+
+    #[test]
+    fn wraps_text() {
+        let fonts = Fonts::load();
+        let embed = EmbedBuilder::new()
+            .description("word ".repeat(40).trim_end())
+            .build()
+            .unwrap();
+        let scene = layout(&embed, Theme::Dark, &fonts);
+        let lines = texts(&scene.items);
+
+        assert!(lines.len() > 1);
+        assert!(lines
+            .iter()
+            .all(|(text, x, _)| { !text.ends_with(' ') && (*x - 32.0).abs() < f32::EPSILON }));
+        assert!(lines.windows(2).all(|pair| pair[0].2 < pair[1].2));
+    }
+
+    #[test]
+    fn places_fields_and_thumbnail() -> Result<(), Box<dyn Error>> {
+        let fonts = Fonts::load();
+        let embed = EmbedBuilder::new()
+            .field(EmbedFieldBuilder::new("a", "1").inline())
+            .field(EmbedFieldBuilder::new("b", "2").inline())
+            .field(EmbedFieldBuilder::new("c", "3").inline())
+            .thumbnail(ImageSource::url("https://example.com/thumbnail.png")?)
+            .build()?;
+        let scene = layout(&embed, Theme::Light, &fonts);
+        let names: Vec<_> = texts(&scene.items)
+            .into_iter()
+            .filter(|(text, ..)| matches!(*text, "a" | "b" | "c"))
+            .collect();
+
+        assert_eq!(3, names.len());
+        assert!((names[0].2 - names[1].2).abs() < f32::EPSILON);
+        assert!(names[1].1 > names[0].1);
+        assert!(names[2].2 > names[0].2);
+        assert!(scene.items.iter().any(|item| matches!(
+            item,
+            Item::Rect { width, height, .. }
+                if (*width - 80.0).abs() < f32::EPSILON && (*height - 80.0).abs() < f32::EPSILON
+        )));
+
+        Ok(())
+    }
+}
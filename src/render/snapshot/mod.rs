@@ -0,0 +1,106 @@
+//! Render embeds into images without a browser.
+//!
+//! Embeds are laid out into a scene of rectangles and text, measured with the
+//! bundled fonts, which is then written as SVG or rasterized into a PNG.
+//! Images aren't downloaded, so they're drawn as placeholders.
+
+mod layout;
+#[cfg(feature = "snapshot-png")]
+mod png;
+mod svg;
+
+#[cfg(feature = "snapshot-png")]
+pub use self::png::png;
+pub use self::svg::svg;
+
+/// Theme of the Discord client that snapshots approximate.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Theme {
+    /// Dark theme.
+    Dark,
+    /// Light theme.
+    Light,
+}
+
+impl Theme {
+    /// Colors of the theme.
+    const fn palette(self) -> Palette {
+        match self {
+            Self::Dark => Palette {
+                background: 0x31_33_38,
+                bar: 0x1e_1f_22,
+                code: 0x1e_1f_22,
+                embed: 0x2b_2d_31,
+                header: 0xf2_f3_f5,
+                link: 0x00_a8_fc,
+                muted: 0xb5_ba_c1,
+                placeholder: 0x1e_1f_22,
+                quote: 0x4e_50_58,
+                text: 0xdb_de_e1,
+            },
+            Self::Light => Palette {
+                background: 0xff_ff_ff,
+                bar: 0xe3_e5_e8,
+                code: 0xeb_ed_ef,
+                embed: 0xf2_f3_f5,
+                header: 0x06_06_07,
+                link: 0x00_6c_e7,
+                muted: 0x5c_5e_66,
+                placeholder: 0xe3_e5_e8,
+                quote: 0xc4_c9_ce,
+                text: 0x31_33_38,
+            },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::Dark
+    }
+}
+
+/// Colors used to draw an embed.
+#[derive(Clone, Copy, Debug)]
+struct Palette {
+    /// Color behind the embed.
+    background: u32,
+    /// Color of the bar of embeds without a color.
+    bar: u32,
+    /// Color behind inline code and code blocks.
+    code: u32,
+    /// Color of the embed.
+    embed: u32,
+    /// Color of the author, title and field names.
+    header: u32,
+    /// Color of links.
+    link: u32,
+    /// Color of the footer.
+    muted: u32,
+    /// Color of images, which aren't downloaded, and spoilers.
+    placeholder: u32,
+    /// Color of the bar of quotes.
+    quote: u32,
+    /// Color of the description and field values.
+    text: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Theme;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        Theme: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+}
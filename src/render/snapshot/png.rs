@@ -0,0 +1,299 @@
+//! Rasterize laid out embeds into PNG images.
+
+use super::{
+    layout::{layout, Face, Fonts, Item},
+    Theme,
+};
+use crate::as_embed::AsEmbed;
+use ab_glyph::{point, Font, GlyphId, OutlineCurve, OutlinedGlyph, Point, ScaleFont};
+
+/// Horizontal shift of glyphs per unit of height, slanting italic text.
+const SLANT: f32 = 0.2;
+
+/// RGB pixels of an image.
+struct Canvas {
+    height: usize,
+    pixels: Vec<u8>,
+    width: usize,
+}
+
+impl Canvas {
+    /// Create a black canvas of a size in whole pixels.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn new(width: f32, height: f32) -> Self {
+        let width = width.ceil().max(1.0) as usize;
+        let height = height.ceil().max(1.0) as usize;
+
+        Self {
+            height,
+            pixels: vec![0; width * height * 3],
+            width,
+        }
+    }
+
+    /// Blend a color into a pixel, ignoring pixels outside of the canvas.
+    fn blend(&mut self, x: i64, y: i64, color: u32, coverage: f32) {
+        let (x, y) = match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) if x < self.width && y < self.height => (x, y),
+            _ => return,
+        };
+        let coverage = coverage.clamp(0.0, 1.0);
+        let offset = (y * self.width + x) * 3;
+
+        for (channel, shift) in [16, 8, 0].into_iter().enumerate() {
+            let old = f32::from(self.pixels[offset + channel]);
+            let new = f32::from(((color >> shift) & 0xff) as u8);
+            self.pixels[offset + channel] = channel_value(old + (new - old) * coverage);
+        }
+    }
+
+    /// Fill a rectangle, antialiasing its edges and rounded corners.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn rect(&mut self, (x, y, width, height): (f32, f32, f32, f32), radius: f32, color: u32) {
+        let radius = radius.min(width / 2.0).min(height / 2.0);
+
+        for py in y.floor() as i64..(y + height).ceil() as i64 {
+            for px in x.floor() as i64..(x + width).ceil() as i64 {
+                let (cx, cy) = (px as f32 + 0.5, py as f32 + 0.5);
+                let horizontal = ((cx + 0.5).min(x + width) - (cx - 0.5).max(x)).clamp(0.0, 1.0);
+                let vertical = ((cy + 0.5).min(y + height) - (cy - 0.5).max(y)).clamp(0.0, 1.0);
+                let mut coverage = horizontal * vertical;
+
+                if radius > 0.0 {
+                    let nearest_x = cx.clamp(x + radius, x + width - radius);
+                    let nearest_y = cy.clamp(y + radius, y + height - radius);
+                    let distance = (cx - nearest_x).hypot(cy - nearest_y);
+                    coverage *= (radius + 0.5 - distance).clamp(0.0, 1.0);
+                }
+
+                self.blend(px, py, color, coverage);
+            }
+        }
+    }
+
+    /// Draw a line of text starting at the start of its baseline.
+    #[allow(clippy::cast_possible_truncation)]
+    fn text(
+        &mut self,
+        fonts: &Fonts,
+        (face, size, italic): (Face, f32, bool),
+        text: &str,
+        (x, y): (f32, f32),
+        color: u32,
+    ) {
+        let font = fonts.face(face);
+        let scale = fonts.scale(face, size);
+        let scaled = font.as_scaled(scale);
+        let mut caret = x;
+        let mut previous: Option<GlyphId> = None;
+
+        for c in text.chars() {
+            let id = scaled.glyph_id(c);
+
+            if let Some(previous) = previous {
+                caret += scaled.kern(previous, id);
+            }
+
+            if let Some(mut outline) = font.outline(id) {
+                if italic {
+                    slant(&mut outline.curves);
+                    let (low, high) = if outline.bounds.min.y < outline.bounds.max.y {
+                        (outline.bounds.min.y, outline.bounds.max.y)
+                    } else {
+                        (outline.bounds.max.y, outline.bounds.min.y)
+                    };
+                    outline.bounds.min.x += low * SLANT;
+                    outline.bounds.max.x += high * SLANT;
+                }
+
+                let glyph = id.with_scale_and_position(scale, point(caret, y));
+                let outlined = OutlinedGlyph::new(glyph, outline, scaled.scale_factor());
+                let bounds = outlined.px_bounds();
+                let (left, top) = (bounds.min.x as i64, bounds.min.y as i64);
+
+                outlined.draw(|gx, gy, coverage| {
+                    self.blend(left + i64::from(gx), top + i64::from(gy), color, coverage);
+                });
+            }
+
+            caret += scaled.h_advance(id);
+            previous = Some(id);
+        }
+    }
+}
+
+/// Render an embed as a PNG image approximating the Discord client.
+///
+/// The embed is laid out like [`svg`] lays it out, and rasterized with the
+/// bundled fonts, so the image looks the same wherever it's rendered. Images
+/// aren't downloaded and are drawn as placeholders.
+///
+/// [`svg`]: super::svg
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{render::{self, Theme}, EmbedBuilder};
+///
+/// let builder = EmbedBuilder::new().title("Server status");
+/// let png = render::png(&builder, Theme::Light);
+///
+/// assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+/// ```
+#[must_use = "rendering the embed has no effect if left unused"]
+pub fn png(embed: &impl AsEmbed, theme: Theme) -> Vec<u8> {
+    let fonts = Fonts::load();
+    let scene = layout(embed.as_embed(), theme, &fonts);
+    let mut canvas = Canvas::new(scene.width, scene.height);
+
+    for item in scene.items {
+        match item {
+            Item::Rect {
+                color,
+                height,
+                radius,
+                width,
+                x,
+                y,
+            } => canvas.rect((x, y, width, height), radius, color),
+            Item::Text {
+                color,
+                face,
+                italic,
+                size,
+                text,
+                x,
+                y,
+            } => canvas.text(&fonts, (face, size, italic), &text, (x, y), color),
+        }
+    }
+
+    encode(&canvas)
+}
+
+/// Round a blended channel into a byte.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn channel_value(value: f32) -> u8 {
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+/// Append a chunk to a PNG image.
+#[allow(clippy::cast_possible_truncation)]
+fn chunk(output: &mut Vec<u8>, kind: [u8; 4], data: &[u8]) {
+    output.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+    let start = output.len();
+    output.extend_from_slice(&kind);
+    output.extend_from_slice(data);
+
+    let crc = crc32(&output[start..]);
+    output.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// CRC-32 checksum of bytes, as used by PNG chunks.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xed_b8_83_20
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+/// Encode a canvas as a PNG image.
+#[allow(clippy::cast_possible_truncation)]
+fn encode(canvas: &Canvas) -> Vec<u8> {
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(canvas.width as u32).to_be_bytes());
+    header.extend_from_slice(&(canvas.height as u32).to_be_bytes());
+    // 8 bits per channel, RGB, default compression, filtering and no
+    // interlacing.
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut data = Vec::with_capacity(canvas.pixels.len() + canvas.height);
+
+    for row in canvas.pixels.chunks(canvas.width * 3) {
+        // Rows aren't filtered.
+        data.push(0);
+        data.extend_from_slice(row);
+    }
+
+    let mut output = b"\x89PNG\r\n\x1a\n".to_vec();
+    chunk(&mut output, *b"IHDR", &header);
+    chunk(
+        &mut output,
+        *b"IDAT",
+        &miniz_oxide::deflate::compress_to_vec_zlib(&data, 6),
+    );
+    chunk(&mut output, *b"IEND", &[]);
+
+    output
+}
+
+/// Slant the curves of an outline for italic text.
+fn slant(curves: &mut [OutlineCurve]) {
+    let slant = |point: &mut Point| point.x += point.y * SLANT;
+
+    for curve in curves {
+        match curve {
+            OutlineCurve::Line(a, b) => {
+                slant(a);
+                slant(b);
+            }
+            OutlineCurve::Quad(a, b, c) => {
+                slant(a);
+                slant(b);
+                slant(c);
+            }
+            OutlineCurve::Cubic(a, b, c, d) => {
+                slant(a);
+                slant(b);
+                slant(c);
+                slant(d);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crc32, png, Canvas};
+    use crate::{render::Theme, EmbedBuilder};
+
+    #[test]
+    fn checksum() {
+        assert_eq!(0xae_42_60_82, crc32(b"IEND"));
+        assert_eq!(0xcb_f4_39_26, crc32(b"123456789"));
+    }
+
+    #[test]
+    fn rounded_rect() {
+        let mut canvas = Canvas::new(10.0, 10.0);
+        canvas.rect((0.0, 0.0, 10.0, 10.0), 4.0, 0xff_ff_ff);
+
+        assert_eq!(0, canvas.pixels[0]);
+        assert_eq!(255, canvas.pixels[(5 * 10 + 5) * 3]);
+    }
+
+    #[test]
+    fn encodes_png() {
+        let embed = EmbedBuilder::new()
+            .title("Title")
+            .description("*italic* text")
+            .build()
+            .unwrap();
+        let output = png(&embed, Theme::Dark);
+
+        assert!(output.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\x02\x28"));
+        assert!(output.ends_with(b"IEND\xae\x42\x60\x82"));
+    }
+}
@@ -0,0 +1,132 @@
+//! Write laid out embeds as SVG.
+
+use super::{
+    layout::{layout, Face, Fonts, Item},
+    Theme,
+};
+use crate::{as_embed::AsEmbed, render::html::escape};
+use std::fmt::Write;
+
+/// Render an embed as an SVG image approximating the Discord client.
+///
+/// Text is laid out with the metrics of the bundled fonts, which the image
+/// refers to by name, so viewers without them installed may draw text
+/// slightly wider or narrower than it was laid out. Images aren't downloaded
+/// and are drawn as placeholders.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{render::{self, Theme}, Color, EmbedBuilder};
+///
+/// let builder = EmbedBuilder::new()
+///     .color(Color::BLURPLE)
+///     .title("Server status")
+///     .description("Everything is **operational**.");
+/// let svg = render::svg(&builder, Theme::Dark);
+///
+/// assert!(svg.starts_with("<svg"));
+/// assert!(svg.contains(">operational</text>"));
+/// ```
+#[must_use = "rendering the embed has no effect if left unused"]
+pub fn svg(embed: &impl AsEmbed, theme: Theme) -> String {
+    let scene = layout(embed.as_embed(), theme, &Fonts::load());
+    let mut output = String::new();
+
+    let _ = write!(
+        output,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+        viewBox=\"0 0 {width} {height}\" font-family=\"'DejaVu Sans', Verdana, sans-serif\" \
+        xml:space=\"preserve\">",
+        width = number(scene.width),
+        height = number(scene.height),
+    );
+
+    for item in scene.items {
+        match item {
+            Item::Rect {
+                color,
+                height,
+                radius,
+                width,
+                x,
+                y,
+            } => {
+                let _ = write!(
+                    output,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"",
+                    number(x),
+                    number(y),
+                    number(width),
+                    number(height)
+                );
+
+                if radius > 0.0 {
+                    let _ = write!(output, " rx=\"{}\"", number(radius));
+                }
+
+                let _ = write!(output, " fill=\"#{color:06x}\"/>");
+            }
+            Item::Text {
+                color,
+                face,
+                italic,
+                size,
+                text,
+                x,
+                y,
+            } => {
+                let _ = write!(
+                    output,
+                    "<text x=\"{}\" y=\"{}\" font-size=\"{}\" fill=\"#{color:06x}\"",
+                    number(x),
+                    number(y),
+                    number(size)
+                );
+
+                if face == Face::Bold {
+                    output.push_str(" font-weight=\"bold\"");
+                }
+
+                if italic {
+                    output.push_str(" font-style=\"italic\"");
+                }
+
+                let _ = write!(output, ">{}</text>", escape(&text));
+            }
+        }
+    }
+
+    output.push_str("</svg>");
+
+    output
+}
+
+/// Round a coordinate to two decimal places.
+fn number(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::svg;
+    use crate::{render::Theme, Color, EmbedBuilder};
+
+    #[test]
+    fn writes_svg() {
+        let embed = EmbedBuilder::new()
+            .color(Color::new(0x00_ff_00).unwrap())
+            .title("<Title>")
+            .description("*a* & __b__")
+            .build()
+            .unwrap();
+        let output = svg(&embed, Theme::Light);
+
+        assert!(output.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"552\""));
+        assert!(output.ends_with("</svg>"));
+        assert!(output.contains("fill=\"#00ff00\""));
+        assert!(output.contains("font-weight=\"bold\">&lt;Title&gt;</text>"));
+        assert!(output.contains("font-style=\"italic\">a</text>"));
+        assert!(output.contains("> &amp; </text>"));
+    }
+}
@@ -0,0 +1,246 @@
+//! Render embeds as the markdown content of a message.
+
+use super::{field_rows, format_timestamp, CONTENT_LENGTH_LIMIT};
+use crate::{as_embed::AsEmbed, length::LengthCounting, markdown, truncate::truncate_text};
+use twilight_model::channel::embed::{Embed, EmbedField};
+
+/// Ellipsis appended to shortened text.
+const ELLIPSIS: &str = "…";
+
+/// Render an embed as markdown that fits in the content of a message.
+///
+/// The author, title and URL are followed by the description, the fields, the
+/// image's URL, and the footer and timestamp. Inline fields that Discord shows
+/// on the same row are rendered on the same line. Author names and footer text
+/// are escaped, since Discord doesn't render markdown in them.
+///
+/// The output fits within [`CONTENT_LENGTH_LIMIT`]. When the embed doesn't fit,
+/// fields are left out from the end with a note of how many were left out,
+/// and then the description is shortened.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{render, EmbedBuilder, EmbedFieldBuilder};
+///
+/// let builder = EmbedBuilder::new()
+///     .title("Server status")
+///     .field(EmbedFieldBuilder::new("Players", "12").inline())
+///     .field(EmbedFieldBuilder::new("Uptime", "3 days").inline())
+///     .field(EmbedFieldBuilder::new("Message of the day", "Be nice"));
+///
+/// assert_eq!(
+///     "**Server status**\n\n\
+///     **Players**: 12 | **Uptime**: 3 days\n\n\
+///     **Message of the day**\nBe nice",
+///     render::text(&builder),
+/// );
+/// ```
+#[must_use = "rendering the embed has no effect if left unused"]
+pub fn text(embed: &impl AsEmbed) -> String {
+    let embed = embed.as_embed();
+    let header = header(embed);
+    let rows = field_rows(&embed.fields, 3)
+        .into_iter()
+        .map(row)
+        .collect::<Vec<_>>();
+    let trailer = trailer(embed);
+    let description = embed.description.as_deref();
+
+    for shown in (0..=rows.len()).rev() {
+        let content = assemble(
+            &header,
+            description,
+            &rows[..shown],
+            rows.len() - shown,
+            &trailer,
+        );
+
+        if content.chars().count() <= CONTENT_LENGTH_LIMIT {
+            return content;
+        }
+    }
+
+    let content = match description {
+        Some(description) => {
+            let without = assemble(&header, Some(""), &[], rows.len(), &trailer);
+            let budget = CONTENT_LENGTH_LIMIT.saturating_sub(without.chars().count());
+            let description =
+                truncate_text(description, budget.max(1), ELLIPSIS, LengthCounting::Chars);
+
+            assemble(&header, description.as_deref(), &[], rows.len(), &trailer)
+        }
+        None => assemble(&header, None, &[], rows.len(), &trailer),
+    };
+
+    truncate_text(
+        &content,
+        CONTENT_LENGTH_LIMIT,
+        ELLIPSIS,
+        LengthCounting::Chars,
+    )
+    .unwrap_or(content)
+}
+
+/// Join the sections of the content, separated by blank lines.
+fn assemble(
+    header: &[String],
+    description: Option<&str>,
+    rows: &[String],
+    omitted: usize,
+    trailer: &[String],
+) -> String {
+    let mut sections = Vec::with_capacity(rows.len() + 4);
+
+    if !header.is_empty() {
+        sections.push(header.join("\n"));
+    }
+
+    if let Some(description) = description {
+        sections.push(description.to_owned());
+    }
+
+    sections.extend(rows.iter().cloned());
+
+    if omitted > 0 {
+        let plural = if omitted == 1 { "" } else { "s" };
+        sections.push(format!("*…and {omitted} more field{plural}*"));
+    }
+
+    if !trailer.is_empty() {
+        sections.push(trailer.join("\n"));
+    }
+
+    sections.join("\n\n")
+}
+
+/// Lines with the author, title and URL.
+fn header(embed: &Embed) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    if let Some(author) = &embed.author {
+        let name = markdown::escape(&author.name);
+
+        lines.push(match &author.url {
+            Some(url) => format!("{name} (<{url}>)"),
+            None => name,
+        });
+    }
+
+    if let Some(title) = &embed.title {
+        lines.push(format!("**{title}**"));
+    }
+
+    if let Some(url) = &embed.url {
+        lines.push(format!("<{url}>"));
+    }
+
+    lines
+}
+
+/// Render a row of fields.
+///
+/// A field on its own row has its name on a line above its value, while
+/// inline fields sharing a row are each rendered on a single line.
+fn row(fields: &[EmbedField]) -> String {
+    if let [field] = fields {
+        if !field.inline {
+            return format!("**{}**\n{}", field.name, field.value);
+        }
+    }
+
+    fields
+        .iter()
+        .map(|field| format!("**{}**: {}", field.name, field.value.replace('\n', " ")))
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+/// Lines with the image's URL, and the footer and timestamp.
+fn trailer(embed: &Embed) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    if let Some(image) = &embed.image {
+        if !image.url.starts_with("attachment://") {
+            lines.push(format!("<{}>", image.url));
+        }
+    }
+
+    let footer = embed
+        .footer
+        .as_ref()
+        .map(|footer| markdown::escape(&footer.text));
+    let timestamp = embed.timestamp.map(format_timestamp);
+
+    match (footer, timestamp) {
+        (Some(footer), Some(timestamp)) => lines.push(format!("{footer} • {timestamp}")),
+        (Some(line), None) | (None, Some(line)) => lines.push(line),
+        (None, None) => {}
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::text;
+    use crate::{
+        render::CONTENT_LENGTH_LIMIT, EmbedAuthorBuilder, EmbedBuilder, EmbedFieldBuilder,
+        EmbedFooterBuilder, ImageSource,
+    };
+    use std::error::Error;
+    use twilight_model::util::Timestamp;
+
+    #[test]
+    fn layout() -> Result<(), Box<dyn Error>> {
+        let embed = EmbedBuilder::new()
+            .author(EmbedAuthorBuilder::new("*author*".to_owned()).url("https://example.com"))
+            .title("Title")
+            .url("https://example.com/title")
+            .description("Description")
+            .field(EmbedFieldBuilder::new("a", "1").inline())
+            .field(EmbedFieldBuilder::new("b", "2\nlines").inline())
+            .field(EmbedFieldBuilder::new("c", "3").inline())
+            .field(EmbedFieldBuilder::new("d", "4").inline())
+            .field(EmbedFieldBuilder::new("e", "5\nlines"))
+            .image(ImageSource::url("https://example.com/image.png")?)
+            .footer(EmbedFooterBuilder::new("_footer_"))
+            .timestamp(Timestamp::from_secs(1_627_923_403)?)
+            .build()?;
+
+        assert_eq!(
+            "\\*author\\* (<https://example.com>)\n**Title**\n<https://example.com/title>\n\n\
+            Description\n\n\
+            **a**: 1 | **b**: 2 lines | **c**: 3\n\n\
+            **d**: 4\n\n\
+            **e**\n5\nlines\n\n\
+            <https://example.com/image.png>\n\\_footer\\_ • 2021-08-02 16:56 UTC",
+            text(&embed)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn limit() {
+        let field_value = "v".repeat(1024);
+        let builder = EmbedBuilder::new()
+            .title("Title")
+            .fields((0..5).map(|idx| EmbedFieldBuilder::new(idx.to_string(), field_value.as_str())))
+            .footer(EmbedFooterBuilder::new("Footer"));
+        let content = text(&builder);
+
+        assert!(content.chars().count() <= CONTENT_LENGTH_LIMIT);
+        assert!(content.contains("**0**"));
+        assert!(!content.contains("**1**"));
+        assert!(content.contains("*…and 4 more fields*"));
+        assert!(content.ends_with("Footer"));
+
+        let builder = builder.description("word ".repeat(1000));
+        let content = text(&builder);
+
+        assert!(content.chars().count() <= CONTENT_LENGTH_LIMIT);
+        assert!(content.starts_with("**Title**\n\nword word"));
+        assert!(content.ends_with("…\n\n*…and 5 more fields*\n\nFooter"));
+    }
+}
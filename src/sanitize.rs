@@ -0,0 +1,419 @@
+//! Sanitize untrusted text before it's put into an embed.
+
+use crate::{markdown, part::EmbedPart};
+
+/// Zero width space, inserted to break up links without visibly changing
+/// text.
+const ZERO_WIDTH_SPACE: char = '\u{200b}';
+
+/// Hosts of invite links, which are broken up after `discord`.
+const INVITE_HOSTS: [&str; 3] = ["discord.gg/", "discord.com/invite", "discordapp.com/invite"];
+
+/// Kind of textual part of an embed, which a [`Sanitizer`] has a policy for.
+///
+/// Unlike [`EmbedPart`], fields aren't identified by their index, since every
+/// field uses the same policies.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum PartKind {
+    /// Name of the author.
+    AuthorName,
+    /// Description.
+    Description,
+    /// Name of any field.
+    FieldName,
+    /// Value of any field.
+    FieldValue,
+    /// Text of the footer.
+    FooterText,
+    /// Title.
+    Title,
+}
+
+impl PartKind {
+    /// Kind of a part of an embed.
+    pub(crate) const fn of(part: EmbedPart) -> Self {
+        match part {
+            EmbedPart::AuthorName => Self::AuthorName,
+            EmbedPart::Description => Self::Description,
+            EmbedPart::FieldName(_) => Self::FieldName,
+            EmbedPart::FieldValue(_) => Self::FieldValue,
+            EmbedPart::FooterText => Self::FooterText,
+            EmbedPart::Title => Self::Title,
+        }
+    }
+}
+
+/// Operations that are applied to text when it's sanitized.
+///
+/// Operations are applied in the order of normalizing line endings, stripping
+/// control characters, neutralizing links and invites, and then escaping
+/// markdown.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::SanitizePolicy;
+///
+/// let policy = SanitizePolicy::NONE.strip_control(true);
+///
+/// assert_eq!("plain *text*", policy.apply("plain\u{202e} *text*"));
+/// ```
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[must_use = "a policy has no effect if left unused"]
+pub struct SanitizePolicy {
+    escape_markdown: bool,
+    neutralize_invites: bool,
+    neutralize_links: bool,
+    normalize_line_endings: bool,
+    strip_control: bool,
+}
+
+impl SanitizePolicy {
+    /// Policy that applies every operation.
+    pub const ALL: Self = Self {
+        escape_markdown: true,
+        neutralize_invites: true,
+        neutralize_links: true,
+        normalize_line_endings: true,
+        strip_control: true,
+    };
+
+    /// Policy that applies no operations, leaving text untouched.
+    pub const NONE: Self = Self {
+        escape_markdown: false,
+        neutralize_invites: false,
+        neutralize_links: false,
+        normalize_line_endings: false,
+        strip_control: false,
+    };
+
+    /// Sanitize text according to the policy.
+    #[must_use = "sanitizing text has no effect if left unused"]
+    pub fn apply(&self, text: &str) -> String {
+        let mut text = if self.normalize_line_endings {
+            text.replace("\r\n", "\n").replace('\r', "\n")
+        } else {
+            text.to_owned()
+        };
+
+        if self.strip_control {
+            text.retain(|c| !is_control(c));
+        }
+
+        if self.neutralize_links {
+            text = text.replace("](", &format!("]{ZERO_WIDTH_SPACE}("));
+        }
+
+        if self.neutralize_invites {
+            text = neutralize_invites(&text);
+        }
+
+        if self.escape_markdown {
+            text = markdown::escape(&text);
+        }
+
+        text
+    }
+
+    /// Escape markdown so that it's rendered literally.
+    ///
+    /// Refer to [`markdown::escape`] for what is escaped. Text that is already
+    /// escaped is escaped again.
+    pub const fn escape_markdown(mut self, escape_markdown: bool) -> Self {
+        self.escape_markdown = escape_markdown;
+
+        self
+    }
+
+    /// Break up invite links so that Discord doesn't link to the invite.
+    ///
+    /// A zero width space is inserted into the host of the invite link.
+    pub const fn neutralize_invites(mut self, neutralize_invites: bool) -> Self {
+        self.neutralize_invites = neutralize_invites;
+
+        self
+    }
+
+    /// Break up masked links so that Discord displays the text and URL
+    /// instead of a link.
+    ///
+    /// A zero width space is inserted between the text and URL of the link.
+    /// This is redundant when markdown is escaped, but it doesn't add visible
+    /// backslashes to parts that don't render markdown.
+    pub const fn neutralize_links(mut self, neutralize_links: bool) -> Self {
+        self.neutralize_links = neutralize_links;
+
+        self
+    }
+
+    /// Replace `\r\n` and lone `\r` line endings with `\n`.
+    pub const fn normalize_line_endings(mut self, normalize_line_endings: bool) -> Self {
+        self.normalize_line_endings = normalize_line_endings;
+
+        self
+    }
+
+    /// Remove control characters other than line feeds and tabs, as well as
+    /// characters that override the direction of text.
+    pub const fn strip_control(mut self, strip_control: bool) -> Self {
+        self.strip_control = strip_control;
+
+        self
+    }
+}
+
+/// Sanitize untrusted text in an embed, with a policy for each part.
+///
+/// This can be passed to [`EmbedBuilder::sanitize`],
+/// [`EmbedAuthorBuilder::sanitize`], [`EmbedFieldBuilder::sanitize`] and
+/// [`EmbedFooterBuilder::sanitize`]. Only text that has already been set is
+/// sanitized.
+///
+/// By default descriptions and field values use [`SanitizePolicy::ALL`].
+/// Titles and field names don't render links, so links and invites are left
+/// as is. Author names and footer text don't render markdown at all, so
+/// they're only stripped of control characters and have their line endings
+/// normalized, since escaping would show backslashes.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{EmbedBuilder, EmbedFooterBuilder, Sanitizer};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let username = "**vesper**";
+/// let query = "[free nitro](https://example.com)";
+///
+/// let embed = EmbedBuilder::new()
+///     .title(format!("Results for {username}"))
+///     .description(query)
+///     .footer(EmbedFooterBuilder::new(format!("Requested by {username}")))
+///     .sanitize(&Sanitizer::new())
+///     .build()?;
+///
+/// assert_eq!(Some(r"Results for \*\*vesper\*\*"), embed.title.as_deref());
+/// assert_eq!(
+///     Some("\\[free nitro\\]\u{200b}(https://example.com)"),
+///     embed.description.as_deref(),
+/// );
+/// assert_eq!("Requested by **vesper**", embed.footer.unwrap().text);
+/// # Ok(()) }
+/// ```
+///
+/// [`EmbedAuthorBuilder::sanitize`]: crate::EmbedAuthorBuilder::sanitize
+/// [`EmbedBuilder::sanitize`]: crate::EmbedBuilder::sanitize
+/// [`EmbedFieldBuilder::sanitize`]: crate::EmbedFieldBuilder::sanitize
+/// [`EmbedFooterBuilder::sanitize`]: crate::EmbedFooterBuilder::sanitize
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[must_use = "a sanitizer has no effect if left unused"]
+pub struct Sanitizer {
+    author_name: SanitizePolicy,
+    description: SanitizePolicy,
+    field_name: SanitizePolicy,
+    field_value: SanitizePolicy,
+    footer_text: SanitizePolicy,
+    title: SanitizePolicy,
+}
+
+impl Sanitizer {
+    /// Create a sanitizer with the default policy for each part.
+    pub const fn new() -> Self {
+        const UNRENDERED: SanitizePolicy = SanitizePolicy::NONE
+            .normalize_line_endings(true)
+            .strip_control(true);
+        const INLINE: SanitizePolicy = UNRENDERED.escape_markdown(true);
+
+        Self {
+            author_name: UNRENDERED,
+            description: SanitizePolicy::ALL,
+            field_name: INLINE,
+            field_value: SanitizePolicy::ALL,
+            footer_text: UNRENDERED,
+            title: INLINE,
+        }
+    }
+
+    /// Create a sanitizer that uses the same policy for every part.
+    pub const fn with_policy(policy: SanitizePolicy) -> Self {
+        Self {
+            author_name: policy,
+            description: policy,
+            field_name: policy,
+            field_value: policy,
+            footer_text: policy,
+            title: policy,
+        }
+    }
+
+    /// Policy for a part of an embed.
+    #[must_use = "retrieving the policy has no effect if left unused"]
+    pub const fn policy(&self, part: EmbedPart) -> SanitizePolicy {
+        self.policy_of(PartKind::of(part))
+    }
+
+    /// Policy for a kind of part of an embed.
+    const fn policy_of(&self, kind: PartKind) -> SanitizePolicy {
+        match kind {
+            PartKind::AuthorName => self.author_name,
+            PartKind::Description => self.description,
+            PartKind::FieldName => self.field_name,
+            PartKind::FieldValue => self.field_value,
+            PartKind::FooterText => self.footer_text,
+            PartKind::Title => self.title,
+        }
+    }
+
+    /// Set the policy for author names.
+    pub const fn author_name(mut self, policy: SanitizePolicy) -> Self {
+        self.author_name = policy;
+
+        self
+    }
+
+    /// Set the policy for descriptions.
+    pub const fn description(mut self, policy: SanitizePolicy) -> Self {
+        self.description = policy;
+
+        self
+    }
+
+    /// Set the policy for field names.
+    pub const fn field_name(mut self, policy: SanitizePolicy) -> Self {
+        self.field_name = policy;
+
+        self
+    }
+
+    /// Set the policy for field values.
+    pub const fn field_value(mut self, policy: SanitizePolicy) -> Self {
+        self.field_value = policy;
+
+        self
+    }
+
+    /// Set the policy for footer text.
+    pub const fn footer_text(mut self, policy: SanitizePolicy) -> Self {
+        self.footer_text = policy;
+
+        self
+    }
+
+    /// Set the policy for titles.
+    pub const fn title(mut self, policy: SanitizePolicy) -> Self {
+        self.title = policy;
+
+        self
+    }
+
+    /// Sanitize text in place according to the policy for a kind of part.
+    pub(crate) fn sanitize(&self, kind: PartKind, text: &mut String) {
+        *text = self.policy_of(kind).apply(text);
+    }
+}
+
+impl Default for Sanitizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a character is a control character or overrides the direction of
+/// text.
+fn is_control(c: char) -> bool {
+    (c.is_control() && c != '\n' && c != '\t')
+        || matches!(
+            c,
+            '\u{061c}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}'
+        )
+}
+
+/// Insert a zero width space after `discord` in the host of invite links.
+fn neutralize_invites(text: &str) -> String {
+    let lowercase = text.to_ascii_lowercase();
+    let mut neutralized = String::with_capacity(text.len());
+    let mut last = 0;
+
+    for (idx, _) in lowercase.match_indices("discord") {
+        let rest = &lowercase[idx..];
+
+        if INVITE_HOSTS.iter().any(|host| rest.starts_with(host)) {
+            let split = idx + "discord".len();
+            neutralized.push_str(&text[last..split]);
+            neutralized.push(ZERO_WIDTH_SPACE);
+            last = split;
+        }
+    }
+
+    neutralized.push_str(&text[last..]);
+
+    neutralized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SanitizePolicy, Sanitizer};
+    use crate::{
+        EmbedAuthorBuilder, EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder, EmbedPart,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(SanitizePolicy: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(Sanitizer: Clone, Debug, Default, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn policy() {
+        assert_eq!("a\r\nb", SanitizePolicy::NONE.apply("a\r\nb"));
+        assert_eq!(
+            "a\nb\nc\td",
+            SanitizePolicy::NONE
+                .normalize_line_endings(true)
+                .strip_control(true)
+                .apply("a\r\nb\rc\t\u{7}\u{202e}\u{2066}d")
+        );
+        assert_eq!(
+            "[a]\u{200b}(b) discord\u{200b}.gg/x DISCORD\u{200b}.com/invite/y discord.com/channels",
+            SanitizePolicy::NONE
+                .neutralize_links(true)
+                .neutralize_invites(true)
+                .apply("[a](b) discord.gg/x DISCORD.com/invite/y discord.com/channels")
+        );
+        assert_eq!(
+            "\\|\\|spoiler\\|\\| discord\u{200b}.gg/x",
+            SanitizePolicy::ALL.apply("||spoiler|| discord.gg/x")
+        );
+    }
+
+    #[test]
+    fn builders() {
+        let sanitizer = Sanitizer::new();
+
+        let author = EmbedAuthorBuilder::new("*a*\r\n".to_owned())
+            .sanitize(&sanitizer)
+            .build();
+        assert_eq!("*a*\n", author.name);
+
+        let footer = EmbedFooterBuilder::new("_b_\u{0}")
+            .sanitize(&sanitizer)
+            .build();
+        assert_eq!("_b_", footer.text);
+
+        let field = EmbedFieldBuilder::new("*c*", "[d](e)")
+            .sanitize(&sanitizer)
+            .build();
+        assert_eq!(r"\*c\*", field.name);
+        assert_eq!("\\[d\\]\u{200b}(e)", field.value);
+
+        let builder = EmbedBuilder::new()
+            .title("`f`")
+            .field(EmbedFieldBuilder::new("g", "~~h~~"))
+            .sanitize(&Sanitizer::new().field_value(SanitizePolicy::NONE));
+        assert_eq!(Some(r"\`f\`"), builder.get_title());
+        assert_eq!("~~h~~", builder.get_fields()[0].value);
+        assert_eq!(
+            SanitizePolicy::NONE,
+            Sanitizer::with_policy(SanitizePolicy::NONE).policy(EmbedPart::Title)
+        );
+    }
+}
@@ -0,0 +1,278 @@
+//! Split oversized embeds into multiple embeds.
+
+use crate::{length::LengthCounting, part::EmbedPart, EmbedBuilder};
+use std::mem;
+use twilight_model::channel::embed::Embed;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Boundaries that text is split on, from most to least preferred.
+const SEPARATORS: &[&str] = &["\n\n", "\n", " "];
+
+/// Split an embed into embeds that each fit within the description, field and
+/// total length limits.
+///
+/// The first embed keeps the title, URL and thumbnail, the last embed receives
+/// the footer, image and timestamp, and every embed repeats the author and
+/// color.
+pub(crate) fn split(mut embed: Embed, counting: LengthCounting) -> Vec<Embed> {
+    let author_length = EmbedPart::AuthorName
+        .text(&embed)
+        .map_or(0, |name| counting.count(name));
+    let footer_length = EmbedPart::FooterText
+        .text(&embed)
+        .map_or(0, |text| counting.count(text));
+    let title_length = EmbedPart::Title
+        .text(&embed)
+        .map_or(0, |title| counting.count(title));
+
+    let description_limit = EmbedBuilder::DESCRIPTION_LENGTH_LIMIT.min(
+        EmbedBuilder::EMBED_LENGTH_LIMIT
+            .saturating_sub(author_length + footer_length + title_length)
+            .max(1),
+    );
+
+    let mut descriptions = embed
+        .description
+        .take()
+        .map_or_else(Vec::new, |description| {
+            split_text(&description, description_limit, counting)
+        })
+        .into_iter();
+    let fields = mem::take(&mut embed.fields);
+    let footer = embed.footer.take();
+    let image = embed.image.take();
+    let timestamp = embed.timestamp.take();
+
+    let follow_up = Embed {
+        author: embed.author.clone(),
+        color: embed.color,
+        description: None,
+        fields: Vec::new(),
+        footer: None,
+        image: None,
+        kind: embed.kind.clone(),
+        provider: None,
+        thumbnail: None,
+        timestamp: None,
+        title: None,
+        url: None,
+        video: None,
+    };
+
+    let mut embeds = Vec::new();
+    let mut current = embed;
+    current.description = descriptions.next();
+
+    for description in descriptions {
+        let next = Embed {
+            description: Some(description),
+            ..follow_up.clone()
+        };
+
+        embeds.push(mem::replace(&mut current, next));
+    }
+
+    let mut length = EmbedPart::total_length(&current, counting) + footer_length;
+
+    for field in fields {
+        let field_length = counting.count(&field.name) + counting.count(&field.value);
+
+        if current.fields.len() == EmbedBuilder::EMBED_FIELD_LIMIT
+            || length + field_length > EmbedBuilder::EMBED_LENGTH_LIMIT
+        {
+            embeds.push(mem::replace(&mut current, follow_up.clone()));
+            length = author_length + footer_length;
+        }
+
+        length += field_length;
+        current.fields.push(field);
+    }
+
+    current.footer = footer;
+    current.image = image;
+    current.timestamp = timestamp;
+    embeds.push(current);
+
+    embeds
+}
+
+/// Split text into chunks no longer than a limit, preferring paragraph, then
+/// line, then word boundaries, and only splitting between grapheme clusters
+/// as a last resort.
+pub(crate) fn split_text(text: &str, limit: usize, counting: LengthCounting) -> Vec<String> {
+    split_on(text, limit, SEPARATORS, counting)
+        .into_iter()
+        .map(|chunk| chunk.trim().to_owned())
+        .filter(|chunk| !chunk.is_empty())
+        .collect()
+}
+
+fn split_on(
+    text: &str,
+    limit: usize,
+    separators: &[&str],
+    counting: LengthCounting,
+) -> Vec<String> {
+    if counting.count(text) <= limit {
+        return vec![text.to_owned()];
+    }
+
+    let (separator, rest) = match separators.split_first() {
+        Some(split) => split,
+        None => return split_graphemes(text, limit, counting),
+    };
+
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+
+    for piece in text.split(separator) {
+        let mut pieces = split_on(piece, limit, rest, counting);
+
+        if pieces.len() > 1 {
+            if !current.is_empty() {
+                chunks.push(mem::take(&mut current));
+            }
+
+            current = pieces.pop().unwrap_or_default();
+            chunks.extend(pieces);
+
+            continue;
+        }
+
+        let piece = pieces.pop().unwrap_or_default();
+
+        if current.is_empty() {
+            current = piece;
+        } else if counting.count(&current) + counting.count(separator) + counting.count(&piece)
+            <= limit
+        {
+            current.push_str(separator);
+            current.push_str(&piece);
+        } else {
+            chunks.push(mem::replace(&mut current, piece));
+        }
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+fn split_graphemes(text: &str, limit: usize, counting: LengthCounting) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut length = 0;
+
+    for grapheme in text.graphemes(true) {
+        let grapheme_length = counting.count(grapheme);
+
+        if length + grapheme_length > limit && !current.is_empty() {
+            chunks.push(mem::take(&mut current));
+            length = 0;
+        }
+
+        current.push_str(grapheme);
+        length += grapheme_length;
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_text;
+    use crate::{
+        Color, EmbedAuthorBuilder, EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder,
+        LengthCounting,
+    };
+    use twilight_model::util::Timestamp;
+
+    #[test]
+    fn text_boundaries() {
+        assert_eq!(
+            split_text("one two\n\nthree four", 9, LengthCounting::Chars),
+            ["one two", "three", "four"]
+        );
+        assert_eq!(
+            split_text("one\ntwo\nthree\n\nfour", 9, LengthCounting::Chars),
+            ["one\ntwo", "three", "four"]
+        );
+        assert_eq!(
+            split_text("abcdefgh", 3, LengthCounting::Chars),
+            ["abc", "def", "gh"]
+        );
+        assert_eq!(split_text("fits", 4, LengthCounting::Chars), ["fits"]);
+    }
+
+    #[test]
+    fn description() {
+        let line = "a".repeat(99);
+        let description = vec![line; 100].join("\n");
+
+        let embeds = EmbedBuilder::new()
+            .author(EmbedAuthorBuilder::new("twilight".to_owned()))
+            .color(Color::new(0x00_43_ff).unwrap())
+            .description(description)
+            .title("title")
+            .build_split()
+            .unwrap();
+
+        assert_eq!(3, embeds.len());
+        assert_eq!(Some("title"), embeds[0].title.as_deref());
+
+        for embed in &embeds {
+            let description = embed.description.as_ref().unwrap();
+            assert!(description.chars().count() <= EmbedBuilder::DESCRIPTION_LENGTH_LIMIT);
+            assert!(description.lines().all(|line| line.len() == 99));
+            assert_eq!("twilight", embed.author.as_ref().unwrap().name);
+            assert_eq!(Some(0x00_43_ff), embed.color);
+        }
+
+        assert!(embeds[1].title.is_none());
+    }
+
+    #[test]
+    fn fields() {
+        let timestamp = Timestamp::from_secs(1_580_608_922).expect("non zero");
+        let mut builder = EmbedBuilder::new()
+            .color(Color::new(0x00_43_ff).unwrap())
+            .footer(EmbedFooterBuilder::new("footer"))
+            .timestamp(timestamp);
+
+        for idx in 0..30 {
+            builder = builder.field(EmbedFieldBuilder::new(idx.to_string(), "value"));
+        }
+
+        let embeds = builder.build_split().unwrap();
+
+        assert_eq!(2, embeds.len());
+        assert_eq!(EmbedBuilder::EMBED_FIELD_LIMIT, embeds[0].fields.len());
+        assert_eq!(5, embeds[1].fields.len());
+        assert_eq!("25", embeds[1].fields[0].name);
+        assert!(embeds[0].footer.is_none());
+        assert!(embeds[0].timestamp.is_none());
+        assert_eq!("footer", embeds[1].footer.as_ref().unwrap().text);
+        assert_eq!(Some(timestamp), embeds[1].timestamp);
+        assert_eq!(Some(0x00_43_ff), embeds[1].color);
+    }
+
+    #[test]
+    fn total_length() {
+        let mut builder = EmbedBuilder::new();
+
+        for _ in 0..10 {
+            builder = builder.field(EmbedFieldBuilder::new("name", "a".repeat(1000)));
+        }
+
+        let embeds = builder.build_split().unwrap();
+
+        assert_eq!(2, embeds.len());
+        assert_eq!(5, embeds[0].fields.len());
+    }
+}
@@ -0,0 +1,1296 @@
+//! Create embeds from templates with placeholders, loaded from JSON or TOML.
+//!
+//! Templates are written in the shape of Discord's JSON embeds. Every text
+//! part, including URLs, can contain placeholders in the form of `{name}`,
+//! which are substituted from a [`TemplateContext`]. Nested values are
+//! accessed with dots, such as `{user.name}` or `{roles.0}`, and literal braces
+//! are written as `{{` and `}}`.
+//!
+//! Fields can be shown conditionally with an `if` key naming a value in the
+//! context, which may be negated with `!`. Missing values, null, false, zero,
+//! and empty text, lists and maps are false. Fields can also be repeated for
+//! each item in a list with an `each` key, with the item available under the
+//! name given by the `as` key, or `item` by default. The position of the item
+//! is available as `{loop.index}`, starting at 0, and `{loop.number}`,
+//! starting at 1:
+//!
+//! ```json
+//! {
+//!     "fields": [
+//!         { "if": "user.premium", "name": "Premium", "value": "Thanks for the support!" },
+//!         { "each": "roles", "as": "role", "name": "Role {loop.number}", "value": "{role.name}" }
+//!     ]
+//! }
+//! ```
+//!
+//! Expanding to more fields than [`EmbedBuilder::EMBED_FIELD_LIMIT`] fails
+//! while rendering. Locations of errors in repeated fields include the index of
+//! the item, such as `fields[1][3].value`.
+//!
+//! # Examples
+//!
+//! ```
+//! use twilight_embed_builder::template::{EmbedTemplate, TemplateContext};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let template = EmbedTemplate::from_json(
+//!     r##"{
+//!         "title": "Welcome, {user.name}!",
+//!         "description": "You're member #{count} of {guild}.",
+//!         "color": "#5865f2",
+//!         "thumbnail": { "url": "{user.avatar}" }
+//!     }"##,
+//! )?;
+//!
+//! #[derive(serde::Serialize)]
+//! struct User {
+//!     avatar: String,
+//!     name: String,
+//! }
+//!
+//! #[derive(serde::Serialize)]
+//! struct Welcome {
+//!     count: u64,
+//!     guild: String,
+//!     user: User,
+//! }
+//!
+//! let context = TemplateContext::from_serialize(&Welcome {
+//!     count: 1000,
+//!     guild: "Twilight".to_owned(),
+//!     user: User {
+//!         avatar: "https://example.com/avatar.png".to_owned(),
+//!         name: "Vesper".to_owned(),
+//!     },
+//! })?;
+//! let embed = template.build(&context)?;
+//!
+//! assert_eq!(Some("Welcome, Vesper!"), embed.title.as_deref());
+//! assert_eq!(
+//!     Some("You're member #1000 of Twilight."),
+//!     embed.description.as_deref(),
+//! );
+//! # Ok(()) }
+//! ```
+
+use crate::{
+    Color, EmbedAuthorBuilder, EmbedBuilder, EmbedError, EmbedErrorType, EmbedFieldBuilder,
+    EmbedFooterBuilder, ImageSource,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use twilight_model::{channel::embed::Embed, util::Timestamp};
+
+/// Error loading or rendering an embed template.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct TemplateError {
+    kind: TemplateErrorType,
+    location: Option<String>,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl TemplateError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &TemplateErrorType {
+        &self.kind
+    }
+
+    /// Location in the template that the error occurred at, such as
+    /// `fields[2].value`.
+    #[must_use = "retrieving the location has no effect if left unused"]
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (TemplateErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn new(kind: TemplateErrorType, location: &str) -> Self {
+        Self {
+            kind,
+            location: Some(location.to_owned()),
+            source: None,
+        }
+    }
+
+    fn with_source(mut self, source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        self.source = Some(source.into());
+
+        self
+    }
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            TemplateErrorType::ColorInvalid { color } => {
+                f.write_str("the color ")?;
+                Display::fmt(color, f)?;
+
+                f.write_str(" is invalid")
+            }
+            TemplateErrorType::CollectionInvalid { name } => {
+                f.write_str("the collection ")?;
+                Display::fmt(name, f)?;
+
+                f.write_str(" is not a list")
+            }
+            TemplateErrorType::ContextInvalid => f.write_str("the context is not a map of values"),
+            TemplateErrorType::EmbedInvalid => f.write_str("the rendered embed is invalid"),
+            TemplateErrorType::FileUnreadable { path } => {
+                f.write_str("the template file ")?;
+                Display::fmt(&path.display(), f)?;
+
+                f.write_str(" could not be read")
+            }
+            TemplateErrorType::FormatUnsupported { path } => {
+                f.write_str("the format of the template file ")?;
+                Display::fmt(&path.display(), f)?;
+
+                f.write_str(" is unsupported")
+            }
+            TemplateErrorType::ImageSourceInvalid { url } => {
+                f.write_str("the image source ")?;
+                Display::fmt(url, f)?;
+
+                f.write_str(" is invalid")
+            }
+            TemplateErrorType::JsonInvalid => f.write_str("the template is not valid JSON"),
+            TemplateErrorType::PlaceholderMissing { name } => {
+                f.write_str("the placeholder ")?;
+                Display::fmt(name, f)?;
+
+                f.write_str(" is not in the context")
+            }
+            TemplateErrorType::PlaceholderNotText { name } => {
+                f.write_str("the placeholder ")?;
+                Display::fmt(name, f)?;
+
+                f.write_str(" is a list or map instead of text")
+            }
+            TemplateErrorType::PlaceholderUnclosed { text } => {
+                f.write_str("a placeholder in ")?;
+                Debug::fmt(text, f)?;
+
+                f.write_str(" is not closed")
+            }
+            TemplateErrorType::TimestampInvalid { timestamp } => {
+                f.write_str("the timestamp ")?;
+                Display::fmt(timestamp, f)?;
+
+                f.write_str(" is invalid")
+            }
+            TemplateErrorType::TooManyFields { count } => {
+                Display::fmt(count, f)?;
+                f.write_str(" fields were expanded, but the limit is ")?;
+
+                Display::fmt(&EmbedBuilder::EMBED_FIELD_LIMIT, f)
+            }
+            #[cfg(feature = "template-toml")]
+            TemplateErrorType::TomlInvalid => f.write_str("the template is not valid TOML"),
+        }?;
+
+        if let Some(location) = &self.location {
+            f.write_str(" at ")?;
+            f.write_str(location)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for TemplateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`TemplateError`] that occurred.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TemplateErrorType {
+    /// Rendered color isn't a valid [`Color`].
+    ///
+    /// [`Color`]: crate::Color
+    ColorInvalid {
+        /// Rendered color.
+        color: String,
+    },
+    /// Collection that a field is repeated for isn't a list.
+    CollectionInvalid {
+        /// Name of the collection.
+        name: String,
+    },
+    /// Value that a context was created from doesn't serialize into a map.
+    ContextInvalid,
+    /// Rendered embed failed to build.
+    ///
+    /// The source of the error is the [`EmbedError`] returned while building
+    /// it.
+    EmbedInvalid,
+    /// Template file couldn't be read.
+    FileUnreadable {
+        /// Path to the file.
+        path: PathBuf,
+    },
+    /// Extension of the template file isn't of a supported format.
+    FormatUnsupported {
+        /// Path to the file.
+        path: PathBuf,
+    },
+    /// Rendered image, thumbnail or icon URL isn't a valid [`ImageSource`].
+    ///
+    /// URLs starting with `attachment://` must be valid for
+    /// [`ImageSource::attachment`] and all other URLs must be valid for
+    /// [`ImageSource::url`].
+    ImageSourceInvalid {
+        /// Rendered URL.
+        url: String,
+    },
+    /// Template is not valid JSON or doesn't match the shape of a template.
+    JsonInvalid,
+    /// Placeholder isn't in the context.
+    PlaceholderMissing {
+        /// Name of the placeholder.
+        name: String,
+    },
+    /// Placeholder refers to a list or map in the context.
+    PlaceholderNotText {
+        /// Name of the placeholder.
+        name: String,
+    },
+    /// Placeholder is opened with `{` but never closed with `}`.
+    PlaceholderUnclosed {
+        /// Text that the placeholder is in.
+        text: String,
+    },
+    /// Rendered timestamp is not a valid ISO 8601 timestamp.
+    TimestampInvalid {
+        /// Rendered timestamp.
+        timestamp: String,
+    },
+    /// Fields expand to more fields than an embed can have.
+    ///
+    /// Refer to [`EmbedBuilder::EMBED_FIELD_LIMIT`] for the limit. The location
+    /// of the error is the field that brought the count over the limit, and
+    /// fields after it aren't rendered.
+    ///
+    /// [`EmbedBuilder::EMBED_FIELD_LIMIT`]: crate::EmbedBuilder::EMBED_FIELD_LIMIT
+    TooManyFields {
+        /// Number of fields that were expanded before rendering stopped.
+        count: usize,
+    },
+    /// Template is not valid TOML or doesn't match the shape of a template.
+    #[cfg(feature = "template-toml")]
+    TomlInvalid,
+}
+
+/// Values that the placeholders of a template are substituted with.
+///
+/// A context can be created from a map of strings, or from any value that
+/// serializes into a map with [`from_serialize`].
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::template::TemplateContext;
+///
+/// let context = TemplateContext::new()
+///     .insert("guild", "Twilight")
+///     .insert("user", "Vesper");
+/// ```
+///
+/// [`from_serialize`]: Self::from_serialize
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[must_use = "a context has no effect if left unused"]
+pub struct TemplateContext(Map<String, Value>);
+
+impl TemplateContext {
+    /// Create an empty context.
+    pub fn new() -> Self {
+        Self(Map::new())
+    }
+
+    /// Create a context from a value that serializes into a map, such as a
+    /// struct or a map.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TemplateErrorType::ContextInvalid`] if the
+    /// value fails to serialize or doesn't serialize into a map.
+    pub fn from_serialize(value: &impl Serialize) -> Result<Self, TemplateError> {
+        let error = TemplateError {
+            kind: TemplateErrorType::ContextInvalid,
+            location: None,
+            source: None,
+        };
+
+        match serde_json::to_value(value) {
+            Ok(Value::Object(map)) => Ok(Self(map)),
+            Ok(_) => Err(error),
+            Err(source) => Err(error.with_source(source)),
+        }
+    }
+
+    /// Insert a text value into the context.
+    pub fn insert(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.0.insert(key.into(), Value::String(value.into()));
+
+        self
+    }
+
+    /// Look up a value by its dotted path, checking scoped values before the
+    /// context itself.
+    fn lookup<'a>(&'a self, scopes: &[(&str, &'a Value)], path: &str) -> Option<&'a Value> {
+        let mut segments = path.split('.');
+        let first = segments.next()?;
+
+        let mut value = scopes
+            .iter()
+            .rev()
+            .find(|(name, _)| *name == first)
+            .map(|(_, value)| *value)
+            .or_else(|| self.0.get(first))?;
+
+        for segment in segments {
+            value = match value {
+                Value::Array(values) => values.get(segment.parse::<usize>().ok()?)?,
+                Value::Object(map) => map.get(segment)?,
+                _ => return None,
+            };
+        }
+
+        Some(value)
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for TemplateContext {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::new(), |context, (key, value)| {
+            context.insert(key, value)
+        })
+    }
+}
+
+impl From<HashMap<String, String>> for TemplateContext {
+    fn from(map: HashMap<String, String>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+/// Template of an embed, in the shape of Discord's JSON embeds.
+///
+/// Refer to the [module-level documentation] for the placeholder syntax and
+/// examples.
+///
+/// [module-level documentation]: self
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct EmbedTemplate {
+    author: Option<AuthorTemplate>,
+    color: Option<ColorTemplate>,
+    description: Option<String>,
+    #[serde(default)]
+    fields: Vec<FieldTemplate>,
+    footer: Option<FooterTemplate>,
+    image: Option<ImageTemplate>,
+    thumbnail: Option<ImageTemplate>,
+    timestamp: Option<String>,
+    title: Option<String>,
+    url: Option<String>,
+}
+
+impl EmbedTemplate {
+    /// Load a template from a file, with the format determined by the file's
+    /// extension.
+    ///
+    /// Files with a `json` extension are loaded as JSON, and files with a
+    /// `toml` extension are loaded as TOML when the `template-toml` feature
+    /// is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TemplateErrorType::FormatUnsupported`] if
+    /// the extension of the file isn't of a supported format.
+    ///
+    /// Returns an error of type [`TemplateErrorType::FileUnreadable`] if the
+    /// file couldn't be read.
+    ///
+    /// Otherwise returns the errors of [`from_json`] or `from_toml`.
+    ///
+    /// [`from_json`]: Self::from_json
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, TemplateError> {
+        Self::_from_file(path.as_ref())
+    }
+
+    fn _from_file(path: &Path) -> Result<Self, TemplateError> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+
+        let supported = extension == Some("json")
+            || (cfg!(feature = "template-toml") && extension == Some("toml"));
+
+        if !supported {
+            return Err(TemplateError {
+                kind: TemplateErrorType::FormatUnsupported {
+                    path: path.to_owned(),
+                },
+                location: None,
+                source: None,
+            });
+        }
+
+        let contents = fs::read_to_string(path).map_err(|source| TemplateError {
+            kind: TemplateErrorType::FileUnreadable {
+                path: path.to_owned(),
+            },
+            location: None,
+            source: Some(Box::new(source)),
+        })?;
+
+        match extension {
+            #[cfg(feature = "template-toml")]
+            Some("toml") => Self::from_toml(&contents),
+            _ => Self::from_json(&contents),
+        }
+    }
+
+    /// Load a template from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TemplateErrorType::JsonInvalid`] if the JSON
+    /// is invalid or doesn't match the shape of a template.
+    pub fn from_json(json: &str) -> Result<Self, TemplateError> {
+        serde_json::from_str(json).map_err(|source| TemplateError {
+            kind: TemplateErrorType::JsonInvalid,
+            location: None,
+            source: Some(Box::new(source)),
+        })
+    }
+
+    /// Load a template from TOML.
+    ///
+    /// Fields are written as an array of tables:
+    ///
+    /// ```toml
+    /// title = "Welcome, {user}!"
+    ///
+    /// [[fields]]
+    /// name = "Rules"
+    /// value = "Read them in {channel}."
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TemplateErrorType::TomlInvalid`] if the TOML
+    /// is invalid or doesn't match the shape of a template.
+    #[cfg(feature = "template-toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, TemplateError> {
+        toml::from_str(toml).map_err(|source| TemplateError {
+            kind: TemplateErrorType::TomlInvalid,
+            location: None,
+            source: Some(Box::new(source)),
+        })
+    }
+
+    /// Render the template into an embed builder, without validating it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TemplateErrorType::PlaceholderMissing`],
+    /// [`TemplateErrorType::PlaceholderNotText`] or
+    /// [`TemplateErrorType::PlaceholderUnclosed`] if a placeholder couldn't be
+    /// substituted.
+    ///
+    /// Returns an error of type [`TemplateErrorType::ColorInvalid`],
+    /// [`TemplateErrorType::ImageSourceInvalid`] or
+    /// [`TemplateErrorType::TimestampInvalid`] if the rendered color, image
+    /// source or timestamp is invalid.
+    pub fn render(&self, context: &TemplateContext) -> Result<EmbedBuilder, TemplateError> {
+        self.render_with_locations(context)
+            .map(|(builder, _)| builder)
+    }
+
+    /// Render the template and build it into an embed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TemplateErrorType::EmbedInvalid`] if the
+    /// rendered embed fails to build, with the [`EmbedError`] as its source
+    /// and the location of the invalid part in the template.
+    ///
+    /// Otherwise returns the errors of [`render`].
+    ///
+    /// [`render`]: Self::render
+    pub fn build(&self, context: &TemplateContext) -> Result<Embed, TemplateError> {
+        let (builder, fields) = self.render_with_locations(context)?;
+
+        builder.build().map_err(|source| TemplateError {
+            kind: TemplateErrorType::EmbedInvalid,
+            location: embed_error_location(&source, &fields),
+            source: Some(Box::new(source)),
+        })
+    }
+
+    /// Render the template, also returning the location in the template of
+    /// each rendered field.
+    fn render_with_locations(
+        &self,
+        context: &TemplateContext,
+    ) -> Result<(EmbedBuilder, Vec<String>), TemplateError> {
+        let render = |text: &str, location: &str| substitute(text, context, &[], location);
+        let mut builder = EmbedBuilder::new();
+        let mut locations = Vec::with_capacity(self.fields.len());
+
+        if let Some(author) = &self.author {
+            let mut author_builder = EmbedAuthorBuilder::new(render(&author.name, "author.name")?);
+
+            if let Some(icon_url) = &author.icon_url {
+                let location = "author.icon_url";
+                author_builder =
+                    author_builder.icon_url(image_source(render(icon_url, location)?, location)?);
+            }
+
+            if let Some(url) = &author.url {
+                author_builder = author_builder.url(render(url, "author.url")?);
+            }
+
+            builder = builder.author(author_builder);
+        }
+
+        if let Some(color) = &self.color {
+            let color = match color {
+                ColorTemplate::Number(color) => Color::new(*color).map_err(|source| {
+                    TemplateError::new(
+                        TemplateErrorType::ColorInvalid {
+                            color: color.to_string(),
+                        },
+                        "color",
+                    )
+                    .with_source(source)
+                }),
+                ColorTemplate::Text(color) => {
+                    let color = render(color, "color")?;
+
+                    Color::from_str(&color).map_err(|source| {
+                        TemplateError::new(TemplateErrorType::ColorInvalid { color }, "color")
+                            .with_source(source)
+                    })
+                }
+            }?;
+
+            builder = builder.color(color);
+        }
+
+        if let Some(description) = &self.description {
+            builder = builder.description(render(description, "description")?);
+        }
+
+        for (field, location) in self.render_fields(context)? {
+            builder = builder.field(field);
+            locations.push(location);
+        }
+
+        if let Some(footer) = &self.footer {
+            let mut footer_builder = EmbedFooterBuilder::new(render(&footer.text, "footer.text")?);
+
+            if let Some(icon_url) = &footer.icon_url {
+                let location = "footer.icon_url";
+                footer_builder =
+                    footer_builder.icon_url(image_source(render(icon_url, location)?, location)?);
+            }
+
+            builder = builder.footer(footer_builder);
+        }
+
+        if let Some(image) = &self.image {
+            let location = "image.url";
+            builder = builder.image(image_source(render(&image.url, location)?, location)?);
+        }
+
+        if let Some(thumbnail) = &self.thumbnail {
+            let location = "thumbnail.url";
+            builder = builder.thumbnail(image_source(render(&thumbnail.url, location)?, location)?);
+        }
+
+        if let Some(timestamp) = &self.timestamp {
+            let timestamp = render(timestamp, "timestamp")?;
+            let parsed = Timestamp::from_str(&timestamp).map_err(|source| {
+                TemplateError::new(
+                    TemplateErrorType::TimestampInvalid { timestamp },
+                    "timestamp",
+                )
+                .with_source(source)
+            })?;
+
+            builder = builder.timestamp(parsed);
+        }
+
+        if let Some(title) = &self.title {
+            builder = builder.title(render(title, "title")?);
+        }
+
+        if let Some(url) = &self.url {
+            builder = builder.url(render(url, "url")?);
+        }
+
+        Ok((builder, locations))
+    }
+
+    /// Render the fields of the template, expanding conditional and repeated
+    /// fields, along with the location in the template of each rendered field.
+    fn render_fields(
+        &self,
+        context: &TemplateContext,
+    ) -> Result<Vec<(EmbedFieldBuilder, String)>, TemplateError> {
+        let mut fields = Vec::with_capacity(self.fields.len());
+
+        // Rendering stops as soon as there are too many fields, so that a
+        // large collection isn't rendered only to be rejected.
+        let too_many_fields = |idx: usize, count: usize| {
+            TemplateError::new(
+                TemplateErrorType::TooManyFields { count },
+                &format!("fields[{idx}]"),
+            )
+        };
+
+        for (idx, field) in self.fields.iter().enumerate() {
+            let location = format!("fields[{idx}]");
+
+            if let Some(collection) = &field.each {
+                let collection = collection.trim();
+                let items = match context.lookup(&[], collection) {
+                    Some(Value::Array(items)) => items,
+                    Some(_) => {
+                        return Err(TemplateError::new(
+                            TemplateErrorType::CollectionInvalid {
+                                name: collection.to_owned(),
+                            },
+                            &format!("{location}.each"),
+                        ));
+                    }
+                    None => {
+                        return Err(TemplateError::new(
+                            TemplateErrorType::PlaceholderMissing {
+                                name: collection.to_owned(),
+                            },
+                            &format!("{location}.each"),
+                        ));
+                    }
+                };
+                let binding = field.binding.as_deref().unwrap_or("item");
+
+                for (item_idx, item) in items.iter().enumerate() {
+                    let mut position = Map::new();
+                    position.insert("index".to_owned(), Value::from(item_idx));
+                    position.insert("number".to_owned(), Value::from(item_idx + 1));
+                    let position = Value::Object(position);
+                    let scopes = [(binding, item), ("loop", &position)];
+
+                    fields.extend(field.render(
+                        context,
+                        &scopes,
+                        format!("{location}[{item_idx}]"),
+                    )?);
+
+                    if fields.len() > EmbedBuilder::EMBED_FIELD_LIMIT {
+                        return Err(too_many_fields(idx, fields.len()));
+                    }
+                }
+            } else {
+                fields.extend(field.render(context, &[], location)?);
+
+                if fields.len() > EmbedBuilder::EMBED_FIELD_LIMIT {
+                    return Err(too_many_fields(idx, fields.len()));
+                }
+            }
+        }
+
+        Ok(fields)
+    }
+}
+
+impl FromStr for EmbedTemplate {
+    type Err = TemplateError;
+
+    /// Load a template from JSON.
+    ///
+    /// This is equivalent to [`EmbedTemplate::from_json`].
+    fn from_str(json: &str) -> Result<Self, Self::Err> {
+        Self::from_json(json)
+    }
+}
+
+/// Template of an embed author.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct AuthorTemplate {
+    icon_url: Option<String>,
+    name: String,
+    url: Option<String>,
+}
+
+/// Template of a color, either as a number or as text such as `#5865f2`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(untagged)]
+enum ColorTemplate {
+    Number(u32),
+    Text(String),
+}
+
+/// Template of an embed field, which may be conditional or repeated for
+/// each item in a collection.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct FieldTemplate {
+    #[serde(rename = "as")]
+    binding: Option<String>,
+    #[serde(rename = "if")]
+    condition: Option<String>,
+    each: Option<String>,
+    #[serde(default)]
+    inline: bool,
+    name: String,
+    value: String,
+}
+
+impl FieldTemplate {
+    /// Render the field with scoped values, returning it along with its
+    /// location if its condition holds.
+    fn render(
+        &self,
+        context: &TemplateContext,
+        scopes: &[(&str, &Value)],
+        location: String,
+    ) -> Result<Option<(EmbedFieldBuilder, String)>, TemplateError> {
+        if let Some(condition) = &self.condition {
+            let condition = condition.trim();
+            let (negated, path) = match condition.strip_prefix('!') {
+                Some(path) => (true, path.trim()),
+                None => (false, condition),
+            };
+
+            if is_truthy(context.lookup(scopes, path)) == negated {
+                return Ok(None);
+            }
+        }
+
+        let name = substitute(&self.name, context, scopes, &format!("{location}.name"))?;
+        let value = substitute(&self.value, context, scopes, &format!("{location}.value"))?;
+        let mut field = EmbedFieldBuilder::new(name, value);
+
+        if self.inline {
+            field = field.inline();
+        }
+
+        Ok(Some((field, location)))
+    }
+}
+
+/// Template of an embed footer.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct FooterTemplate {
+    icon_url: Option<String>,
+    text: String,
+}
+
+/// Template of an embed image or thumbnail.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct ImageTemplate {
+    url: String,
+}
+
+/// Whether a value is considered true by a condition.
+///
+/// Missing values, null, false, zero, and empty text, lists and maps are
+/// false, and everything else is true.
+fn is_truthy(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => false,
+        Some(Value::Array(values)) => !values.is_empty(),
+        Some(Value::Bool(value)) => *value,
+        Some(Value::Number(value)) => value.as_f64() != Some(0.0),
+        Some(Value::Object(map)) => !map.is_empty(),
+        Some(Value::String(value)) => !value.is_empty(),
+    }
+}
+
+/// Location in a template of the part that an embed error is about.
+fn embed_error_location(error: &EmbedError, fields: &[String]) -> Option<String> {
+    let field = |part: &str| {
+        error
+            .field_index()
+            .and_then(|idx| fields.get(idx))
+            .map(|location| format!("{location}.{part}"))
+    };
+
+    match error.kind() {
+        EmbedErrorType::AuthorNameBlank { .. }
+        | EmbedErrorType::AuthorNameEmpty { .. }
+        | EmbedErrorType::AuthorNameTooLong { .. } => Some("author.name".to_owned()),
+        EmbedErrorType::ColorNotRgb { .. } | EmbedErrorType::ColorZero => Some("color".to_owned()),
+        EmbedErrorType::DescriptionBlank { .. }
+        | EmbedErrorType::DescriptionEmpty { .. }
+        | EmbedErrorType::DescriptionTooLong { .. } => Some("description".to_owned()),
+        EmbedErrorType::FieldNameBlank { .. }
+        | EmbedErrorType::FieldNameEmpty { .. }
+        | EmbedErrorType::FieldNameTooLong { .. } => field("name"),
+        EmbedErrorType::FieldValueBlank { .. }
+        | EmbedErrorType::FieldValueEmpty { .. }
+        | EmbedErrorType::FieldValueTooLong { .. } => field("value"),
+        EmbedErrorType::FooterTextBlank { .. }
+        | EmbedErrorType::FooterTextEmpty { .. }
+        | EmbedErrorType::FooterTextTooLong { .. } => Some("footer.text".to_owned()),
+        EmbedErrorType::TitleBlank { .. }
+        | EmbedErrorType::TitleEmpty { .. }
+        | EmbedErrorType::TitleTooLong { .. } => Some("title".to_owned()),
+        EmbedErrorType::TooManyFields { .. } => Some("fields".to_owned()),
+        EmbedErrorType::TotalContentTooLarge { .. } => None,
+    }
+}
+
+/// Create an image source from a rendered URL.
+fn image_source(url: String, location: &str) -> Result<ImageSource, TemplateError> {
+    ImageSource::parse(url.clone()).map_err(|source| {
+        TemplateError::new(TemplateErrorType::ImageSourceInvalid { url }, location)
+            .with_source(source)
+    })
+}
+
+/// Substitute the placeholders in text with values from a context.
+fn substitute(
+    text: &str,
+    context: &TemplateContext,
+    scopes: &[(&str, &Value)],
+    location: &str,
+) -> Result<String, TemplateError> {
+    let mut rendered = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(idx) = rest.find(['{', '}']) {
+        rendered.push_str(&rest[..idx]);
+        let tail = &rest[idx..];
+
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            rendered.push_str(&tail[..1]);
+            rest = &tail[2..];
+
+            continue;
+        }
+
+        if let Some(tail) = tail.strip_prefix('}') {
+            rendered.push('}');
+            rest = tail;
+
+            continue;
+        }
+
+        let end = tail.find('}').ok_or_else(|| {
+            TemplateError::new(
+                TemplateErrorType::PlaceholderUnclosed {
+                    text: text.to_owned(),
+                },
+                location,
+            )
+        })?;
+        let name = tail[1..end].trim();
+
+        match context.lookup(scopes, name) {
+            Some(Value::Array(_) | Value::Object(_)) => {
+                return Err(TemplateError::new(
+                    TemplateErrorType::PlaceholderNotText {
+                        name: name.to_owned(),
+                    },
+                    location,
+                ));
+            }
+            Some(Value::Bool(value)) => rendered.push_str(&value.to_string()),
+            Some(Value::Null) => {}
+            Some(Value::Number(value)) => rendered.push_str(&value.to_string()),
+            Some(Value::String(value)) => rendered.push_str(value),
+            None => {
+                return Err(TemplateError::new(
+                    TemplateErrorType::PlaceholderMissing {
+                        name: name.to_owned(),
+                    },
+                    location,
+                ));
+            }
+        }
+
+        rest = &tail[end + 1..];
+    }
+
+    rendered.push_str(rest);
+
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EmbedTemplate, TemplateContext, TemplateError, TemplateErrorType};
+    use crate::{EmbedError, EmbedErrorType};
+    use serde::Serialize;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{collections::HashMap, error::Error, fmt::Debug, str::FromStr};
+
+    assert_impl_all!(TemplateErrorType: Debug, Send, Sync);
+    assert_impl_all!(TemplateError: Error, Send, Sync);
+    assert_fields!(TemplateErrorType::ColorInvalid: color);
+    assert_fields!(TemplateErrorType::CollectionInvalid: name);
+    assert_fields!(TemplateErrorType::FileUnreadable: path);
+    assert_fields!(TemplateErrorType::FormatUnsupported: path);
+    assert_fields!(TemplateErrorType::ImageSourceInvalid: url);
+    assert_fields!(TemplateErrorType::PlaceholderMissing: name);
+    assert_fields!(TemplateErrorType::PlaceholderNotText: name);
+    assert_fields!(TemplateErrorType::PlaceholderUnclosed: text);
+    assert_fields!(TemplateErrorType::TimestampInvalid: timestamp);
+    assert_fields!(TemplateErrorType::TooManyFields: count);
+    assert_impl_all!(
+        TemplateContext: Clone,
+        Debug,
+        Default,
+        From<HashMap<String, String>>,
+        Send,
+        Sync
+    );
+    assert_impl_all!(EmbedTemplate: Clone, Debug, FromStr, Send, Sync);
+
+    const TEMPLATE: &str = r##"{
+        "author": { "name": "{user.name}", "icon_url": "{user.avatar}" },
+        "color": "#{color}",
+        "description": "Welcome to {guild}, {{literally}}.",
+        "fields": [
+            { "name": "Roles", "value": "{user.roles.0} and {user.roles.1}", "inline": true },
+            { "name": "Member", "value": "#{count}" }
+        ],
+        "footer": { "text": "{guild}" },
+        "image": { "url": "attachment://{file}" },
+        "timestamp": "{joined}",
+        "title": "Hello",
+        "url": "https://example.com/{user.name}"
+    }"##;
+
+    #[derive(Serialize)]
+    struct Item {
+        name: &'static str,
+        shown: bool,
+    }
+
+    #[derive(Serialize)]
+    struct User {
+        avatar: &'static str,
+        name: &'static str,
+        roles: [&'static str; 2],
+    }
+
+    #[derive(Serialize)]
+    struct Context {
+        color: &'static str,
+        count: u64,
+        file: &'static str,
+        guild: &'static str,
+        joined: &'static str,
+        user: User,
+    }
+
+    fn context() -> TemplateContext {
+        TemplateContext::from_serialize(&Context {
+            color: "5865f2",
+            count: 7,
+            file: "banner.png",
+            guild: "Twilight",
+            joined: "2021-01-01T00:00:00+00:00",
+            user: User {
+                avatar: "https://example.com/avatar.png",
+                name: "vesper",
+                roles: ["Admin", "Mod"],
+            },
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn build() -> Result<(), Box<dyn Error>> {
+        let embed = EmbedTemplate::from_json(TEMPLATE)?.build(&context())?;
+
+        assert_eq!("vesper", embed.author.as_ref().unwrap().name);
+        assert_eq!(Some(0x58_65_f2), embed.color);
+        assert_eq!(
+            Some("Welcome to Twilight, {literally}."),
+            embed.description.as_deref()
+        );
+        assert_eq!("Admin and Mod", embed.fields[0].value);
+        assert!(embed.fields[0].inline);
+        assert_eq!("#7", embed.fields[1].value);
+        assert_eq!("Twilight", embed.footer.unwrap().text);
+        assert_eq!("attachment://banner.png", embed.image.unwrap().url);
+        assert!(embed.timestamp.is_some());
+        assert_eq!(Some("https://example.com/vesper"), embed.url.as_deref());
+
+        Ok(())
+    }
+
+    #[test]
+    fn context_map() -> Result<(), Box<dyn Error>> {
+        let template = EmbedTemplate::from_json(r#"{ "title": "{a} {b}" }"#)?;
+        let context = [("a", "one"), ("b", "two")]
+            .into_iter()
+            .collect::<TemplateContext>();
+
+        assert_eq!(Some("one two"), template.build(&context)?.title.as_deref());
+        assert!(matches!(
+            TemplateContext::from_serialize(&"text").unwrap_err().kind(),
+            TemplateErrorType::ContextInvalid
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors() -> Result<(), Box<dyn Error>> {
+        let context = TemplateContext::new().insert("empty", "");
+
+        let error = EmbedTemplate::from_json(r#"{ "description": "{missing}" }"#)?
+            .build(&context)
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            TemplateErrorType::PlaceholderMissing { name } if name == "missing"
+        ));
+        assert_eq!(Some("description"), error.location());
+        assert_eq!(
+            "the placeholder missing is not in the context at description",
+            error.to_string()
+        );
+
+        let error = EmbedTemplate::from_json(r#"{ "title": "{unclosed" }"#)?
+            .build(&context)
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            TemplateErrorType::PlaceholderUnclosed { .. }
+        ));
+
+        let error = EmbedTemplate::from_json(r#"{ "thumbnail": { "url": "ftp://{empty}" } }"#)?
+            .build(&context)
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            TemplateErrorType::ImageSourceInvalid { url } if url == "ftp://"
+        ));
+        assert_eq!(Some("thumbnail.url"), error.location());
+
+        let error = EmbedTemplate::from_json(
+            r#"{ "fields": [{ "name": "a", "value": "b" }, { "name": "c", "value": "{empty}" }] }"#,
+        )?
+        .build(&context)
+        .unwrap_err();
+        assert!(matches!(error.kind(), TemplateErrorType::EmbedInvalid));
+        assert_eq!(Some("fields[1].value"), error.location());
+        let source = error
+            .into_source()
+            .unwrap()
+            .downcast::<EmbedError>()
+            .unwrap();
+        assert!(matches!(
+            source.kind(),
+            EmbedErrorType::FieldValueEmpty { .. }
+        ));
+
+        assert!(matches!(
+            EmbedTemplate::from_json(r#"{ "titel": "typo" }"#)
+                .unwrap_err()
+                .kind(),
+            TemplateErrorType::JsonInvalid
+        ));
+        assert!(matches!(
+            EmbedTemplate::from_file("template.yaml")
+                .unwrap_err()
+                .kind(),
+            TemplateErrorType::FormatUnsupported { .. }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn conditions() -> Result<(), Box<dyn Error>> {
+        let template = EmbedTemplate::from_json(
+            r#"{ "fields": [
+                { "if": "shown", "name": "a", "value": "shown" },
+                { "if": "!shown", "name": "b", "value": "hidden" },
+                { "if": "absent", "name": "c", "value": "hidden" },
+                { "if": "!empty", "name": "d", "value": "{missing}" }
+            ] }"#,
+        )?;
+        let context = TemplateContext::new()
+            .insert("shown", "yes")
+            .insert("empty", "");
+
+        let error = template.build(&context).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            TemplateErrorType::PlaceholderMissing { name } if name == "missing"
+        ));
+        assert_eq!(Some("fields[3].value"), error.location());
+
+        let embed = template.build(&context.insert("missing", "shown"))?;
+        let values = embed
+            .fields
+            .iter()
+            .map(|field| field.value.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(["shown", "shown"], values.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn loops() -> Result<(), Box<dyn Error>> {
+        let template = EmbedTemplate::from_json(
+            r#"{ "fields": [
+                { "name": "Roles", "value": "{count}" },
+                { "each": "user.roles", "as": "role", "name": "{loop.number}", "value": "{role}" },
+                { "each": "items", "if": "item.shown", "name": "{item.name}", "value": "{loop.index}" }
+            ] }"#,
+        )?;
+
+        let items = [
+            Item {
+                name: "a",
+                shown: true,
+            },
+            Item {
+                name: "b",
+                shown: false,
+            },
+            Item {
+                name: "c",
+                shown: true,
+            },
+        ];
+        let mut values = HashMap::new();
+        values.insert("count", serde_json::json!(2));
+        values.insert("items", serde_json::to_value(&items)?);
+        values.insert("user", serde_json::json!({ "roles": ["Admin", "Mod"] }));
+
+        let embed = template.build(&TemplateContext::from_serialize(&values)?)?;
+        let fields = embed
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.value.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            [
+                ("Roles", "2"),
+                ("1", "Admin"),
+                ("2", "Mod"),
+                ("a", "0"),
+                ("c", "2")
+            ],
+            fields.as_slice()
+        );
+
+        values.insert("items", serde_json::json!([{ "name": "", "shown": true }]));
+        let error = template
+            .build(&TemplateContext::from_serialize(&values)?)
+            .unwrap_err();
+        assert!(matches!(error.kind(), TemplateErrorType::EmbedInvalid));
+        assert_eq!(Some("fields[2][0].name"), error.location());
+
+        values.insert("items", serde_json::json!("not a list"));
+        let error = template
+            .build(&TemplateContext::from_serialize(&values)?)
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            TemplateErrorType::CollectionInvalid { name } if name == "items"
+        ));
+        assert_eq!(Some("fields[2].each"), error.location());
+
+        Ok(())
+    }
+
+    #[test]
+    fn field_limit() -> Result<(), Box<dyn Error>> {
+        let template = EmbedTemplate::from_json(
+            r#"{ "fields": [
+                { "name": "first", "value": "value" },
+                { "each": "items", "name": "{item}", "value": "value" },
+                { "name": "last", "value": "value" }
+            ] }"#,
+        )?;
+        let context = |count: usize| {
+            let mut values = HashMap::new();
+            values.insert("items", (0..count).collect::<Vec<_>>());
+
+            TemplateContext::from_serialize(&values)
+        };
+
+        assert_eq!(25, template.build(&context(23)?)?.fields.len());
+
+        let error = template.render(&context(24)?).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            TemplateErrorType::TooManyFields { count: 26 }
+        ));
+        assert_eq!(Some("fields[2]"), error.location());
+
+        let error = template.render(&context(100_000)?).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            TemplateErrorType::TooManyFields { count: 26 }
+        ));
+        assert_eq!(Some("fields[1]"), error.location());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "template-toml")]
+    #[test]
+    fn toml() -> Result<(), Box<dyn Error>> {
+        let template = EmbedTemplate::from_toml(
+            r#"
+            title = "Welcome, {user.name}!"
+            color = 0x5865f2
+
+            [[fields]]
+            name = "Roles"
+            value = "{user.roles.0}"
+            "#,
+        )?;
+        let embed = template.build(&context())?;
+
+        assert_eq!(Some("Welcome, vesper!"), embed.title.as_deref());
+        assert_eq!(Some(0x58_65_f2), embed.color);
+        assert_eq!("Admin", embed.fields[0].value);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,595 @@
+//! Truncate embed text to fit within its limits.
+
+use crate::{builder, length::LengthCounting, part::EmbedPart, EmbedBuilder};
+use twilight_model::channel::embed::Embed;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Delimiters of markdown constructs that are wrapped around text.
+///
+/// Longer delimiters come first so that `**` is matched before `*`.
+const DELIMITERS: &[&str] = &["```", "**", "__", "~~", "||", "`", "*", "_"];
+
+/// Part of an embed that was truncated.
+///
+/// This is returned from [`EmbedBuilder::build_truncated`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Truncation {
+    length: usize,
+    original_length: usize,
+    part: EmbedPart,
+}
+
+impl Truncation {
+    /// Length of the part after truncation, including the ellipsis.
+    #[must_use = "retrieving the length has no effect if left unused"]
+    pub const fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Length of the part before truncation.
+    #[must_use = "retrieving the original length has no effect if left unused"]
+    pub const fn original_length(&self) -> usize {
+        self.original_length
+    }
+
+    /// Part of the embed that was truncated.
+    #[must_use = "retrieving the part has no effect if left unused"]
+    pub const fn part(&self) -> EmbedPart {
+        self.part
+    }
+
+    /// Length that the part was shortened by.
+    ///
+    /// This is never zero, because parts that truncating doesn't shorten,
+    /// such as a single character that's longer than its limit, are left as
+    /// they are and not recorded.
+    #[must_use = "retrieving the removed length has no effect if left unused"]
+    pub const fn removed(&self) -> usize {
+        self.original_length - self.length
+    }
+}
+
+/// Truncate every part of an embed that is too long, and then the embed as a
+/// whole if its total length is too large.
+pub(crate) fn truncate(
+    embed: &mut Embed,
+    ellipsis: &str,
+    counting: LengthCounting,
+) -> Vec<Truncation> {
+    let mut truncations = Vec::new();
+
+    for part in EmbedPart::present(embed) {
+        truncate_part(
+            embed,
+            part,
+            part.limit(),
+            ellipsis,
+            counting,
+            &mut truncations,
+        );
+    }
+
+    let mut total = EmbedPart::total_length(embed, counting);
+
+    for part in overflow_order(embed) {
+        if total <= EmbedBuilder::EMBED_LENGTH_LIMIT {
+            break;
+        }
+
+        let length = part.text(embed).map_or(0, |text| counting.count(text));
+        let limit = length
+            .saturating_sub(total - EmbedBuilder::EMBED_LENGTH_LIMIT)
+            .max(1);
+
+        total -= truncate_part(embed, part, limit, ellipsis, counting, &mut truncations);
+    }
+
+    truncations
+}
+
+/// Parts in the order they're shortened when the embed's total length is too
+/// large, starting with the parts most likely to hold long content.
+fn overflow_order(embed: &Embed) -> Vec<EmbedPart> {
+    let fields = (0..embed.fields.len()).rev();
+
+    let mut parts = vec![EmbedPart::Description];
+    parts.extend(fields.clone().map(EmbedPart::FieldValue));
+    parts.push(EmbedPart::FooterText);
+    parts.extend(fields.map(EmbedPart::FieldName));
+    parts.push(EmbedPart::Title);
+    parts.push(EmbedPart::AuthorName);
+
+    parts
+}
+
+/// Truncate a part to a limit, recording the truncation and returning the
+/// length removed.
+///
+/// The part is left as it is if truncating it wouldn't shorten it.
+fn truncate_part(
+    embed: &mut Embed,
+    part: EmbedPart,
+    limit: usize,
+    ellipsis: &str,
+    counting: LengthCounting,
+    truncations: &mut Vec<Truncation>,
+) -> usize {
+    let text = match part.text_mut(embed) {
+        Some(text) => text,
+        None => return 0,
+    };
+
+    let original_length = counting.count(text);
+
+    let truncated = match truncate_text(text, limit, ellipsis, counting) {
+        Some(truncated) => truncated,
+        None => return 0,
+    };
+
+    let length = counting.count(&truncated);
+
+    if length >= original_length {
+        return 0;
+    }
+
+    *text = truncated;
+
+    if let Some(truncation) = truncations
+        .iter_mut()
+        .find(|truncation| truncation.part == part)
+    {
+        truncation.length = length;
+    } else {
+        truncations.push(Truncation {
+            length,
+            original_length,
+            part,
+        });
+    }
+
+    original_length - length
+}
+
+/// Truncate text to a limit with an ellipsis, returning [`None`] if the text
+/// already fits.
+///
+/// The ellipsis is dropped if it doesn't fit within the limit by itself.
+/// Leading whitespace and invisible characters are dropped, and the start of
+/// the first visible character is kept even if it doesn't fit, so that the
+/// text is never truncated to something empty or blank.
+pub(crate) fn truncate_text(
+    text: &str,
+    limit: usize,
+    ellipsis: &str,
+    counting: LengthCounting,
+) -> Option<String> {
+    if counting.count(text) <= limit {
+        return None;
+    }
+
+    let text = text
+        .find(|character| !builder::is_invisible(character))
+        .map_or(text, |start| &text[start..]);
+
+    let ellipsis_length = counting.count(ellipsis);
+    let (mut ellipsis, budget) = if ellipsis_length < limit {
+        (ellipsis, limit - ellipsis_length)
+    } else {
+        ("", limit)
+    };
+
+    let mut cut = 0;
+    let mut length = 0;
+
+    for (idx, grapheme) in text.grapheme_indices(true) {
+        length += counting.count(grapheme);
+
+        if length > budget {
+            break;
+        }
+
+        cut = idx + grapheme.len();
+    }
+
+    let mut prefix = text[..markdown_boundary(text, cut)].trim_end();
+
+    if prefix.is_empty() && builder::is_blank(ellipsis) {
+        prefix = text[..cut].trim_end();
+    }
+
+    if prefix.is_empty() && builder::is_blank(ellipsis) {
+        let end = text
+            .char_indices()
+            .map(|(idx, character)| idx + character.len_utf8())
+            .take_while(|end| counting.count(&text[..*end]) <= budget)
+            .last()
+            .or_else(|| text.chars().next().map(char::len_utf8))
+            .unwrap_or_default();
+
+        prefix = &text[..end];
+        ellipsis = "";
+    }
+
+    Some(format!("{prefix}{ellipsis}"))
+}
+
+/// Move a cut in text to before any markdown construct that it would split,
+/// such as a masked link or bold text.
+///
+/// Only constructs that start before the cut are looked at, and the text is
+/// searched for each kind of closing delimiter at most once, so that long
+/// untrusted text doesn't take quadratic time.
+fn markdown_boundary(text: &str, cut: usize) -> usize {
+    let mut finder = Finder::new(text);
+    let mut idx = 0;
+
+    while idx < cut {
+        let rest = &text[idx..];
+
+        let (length, skip) = if rest.starts_with("http://") || rest.starts_with("https://") {
+            (
+                Some(finder.find(Needle::Whitespace, idx).unwrap_or(text.len()) - idx),
+                0,
+            )
+        } else if rest.starts_with('<') {
+            (bracketed_length(&mut finder, idx), 1)
+        } else if rest.starts_with('[') {
+            (masked_link_length(&mut finder, idx), 1)
+        } else if let Some(delimiter) = DELIMITERS.iter().find(|d| rest.starts_with(**d)) {
+            (
+                delimited_length(&mut finder, idx, delimiter),
+                delimiter.len(),
+            )
+        } else {
+            (None, rest.chars().next().map_or(1, char::len_utf8))
+        };
+
+        match length {
+            Some(length) if idx + length > cut => return idx,
+            Some(length) => idx += length,
+            None => idx += skip,
+        }
+    }
+
+    cut
+}
+
+/// Length of a construct such as a mention, emoji or timestamp wrapped in
+/// angle brackets.
+fn bracketed_length(finder: &mut Finder<'_>, idx: usize) -> Option<usize> {
+    let end = finder.find(Needle::AngleBracket, idx + 1)?;
+
+    finder.text[end..].starts_with('>').then(|| end + 1 - idx)
+}
+
+/// Length of a masked link in the form of `[text](url)`.
+fn masked_link_length(finder: &mut Finder<'_>, idx: usize) -> Option<usize> {
+    let close = finder.find(Needle::LinkMiddle, idx)?;
+
+    if finder
+        .find(Needle::Newline, idx)
+        .map_or(false, |newline| newline < close)
+    {
+        return None;
+    }
+
+    let url_end = finder.find(Needle::LinkEnd, close + 2)?;
+
+    finder.text[url_end..]
+        .starts_with(')')
+        .then(|| url_end + 1 - idx)
+}
+
+/// Length of text wrapped in a delimiter, such as `**bold**` or `` `code` ``.
+fn delimited_length(finder: &mut Finder<'_>, idx: usize, delimiter: &'static str) -> Option<usize> {
+    let inner = idx + delimiter.len();
+
+    // Emphasis can't be opened by a delimiter followed by whitespace, such as
+    // a list bullet.
+    if !delimiter.starts_with('`') && finder.text[inner..].starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let end = finder
+        .find(Needle::Delimiter(delimiter), inner)
+        .filter(|end| *end > inner)?;
+
+    Some(end + delimiter.len() - idx)
+}
+
+/// What ends a markdown construct.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Needle {
+    /// End of a construct wrapped in angle brackets, or what prevents it
+    /// from being one.
+    AngleBracket,
+    /// Closing delimiter of emphasis or code.
+    Delimiter(&'static str),
+    /// End of the URL of a masked link.
+    LinkEnd,
+    /// Boundary between the text and URL of a masked link.
+    LinkMiddle,
+    /// Line break.
+    Newline,
+    /// Whitespace, which ends a URL.
+    Whitespace,
+}
+
+impl Needle {
+    /// Length of an occurrence in bytes.
+    const fn len(self) -> usize {
+        match self {
+            Self::Delimiter(delimiter) => delimiter.len(),
+            Self::LinkMiddle => 2,
+            Self::AngleBracket | Self::LinkEnd | Self::Newline | Self::Whitespace => 1,
+        }
+    }
+
+    /// Byte index of the first occurrence in text.
+    fn find(self, text: &str) -> Option<usize> {
+        match self {
+            Self::AngleBracket => text.find(|c: char| c == '<' || c == '>' || c.is_whitespace()),
+            Self::Delimiter(delimiter) => text.find(delimiter),
+            Self::LinkEnd => text.find(|c: char| c == ')' || c.is_whitespace()),
+            Self::LinkMiddle => text.find("]("),
+            Self::Newline => text.find('\n'),
+            Self::Whitespace => text.find(char::is_whitespace),
+        }
+    }
+}
+
+/// Search text for needles, remembering the results so that the same text
+/// isn't searched for a needle again.
+struct Finder<'a> {
+    /// Needles with the index that they were last searched from and the
+    /// first occurrence at or after it.
+    searches: Vec<(Needle, usize, Option<usize>)>,
+    text: &'a str,
+}
+
+impl<'a> Finder<'a> {
+    const fn new(text: &'a str) -> Self {
+        Self {
+            searches: Vec::new(),
+            text,
+        }
+    }
+
+    /// Byte index of the first occurrence of a needle at or after an index.
+    fn find(&mut self, needle: Needle, from: usize) -> Option<usize> {
+        let position = self.searches.iter().position(|search| search.0 == needle);
+
+        let found = match position.map(|position| self.searches[position]) {
+            // Only the text before the previous search needs to be searched,
+            // including occurrences that overlap its start.
+            Some((_, searched, found)) if from <= searched => {
+                let mut end = (searched + needle.len() - 1).min(self.text.len());
+
+                while !self.text.is_char_boundary(end) {
+                    end += 1;
+                }
+
+                needle
+                    .find(&self.text[from..end])
+                    .map(|idx| from + idx)
+                    .filter(|idx| *idx < searched)
+                    .or(found)
+            }
+            // There is no occurrence after the previous search.
+            Some((_, _, None)) => None,
+            Some((_, _, Some(found))) if from <= found => Some(found),
+            _ => needle.find(&self.text[from..]).map(|idx| from + idx),
+        };
+
+        match position {
+            Some(position) => self.searches[position] = (needle, from, found),
+            None => self.searches.push((needle, from, found)),
+        }
+
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{truncate_part, truncate_text, Truncation};
+    use crate::LengthCounting;
+    use crate::{
+        EmbedAuthorBuilder, EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder, EmbedPart,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(Truncation: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn text_fits() {
+        assert!(truncate_text("twilight", 8, "…", LengthCounting::Chars).is_none());
+        assert_eq!(
+            Some("twil…"),
+            truncate_text("twilight", 5, "…", LengthCounting::Chars).as_deref()
+        );
+        assert_eq!(
+            Some("tw"),
+            truncate_text("twilight", 2, "...", LengthCounting::Chars).as_deref()
+        );
+    }
+
+    #[test]
+    fn text_graphemes() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+
+        assert_eq!(
+            Some(format!("{family}…")),
+            truncate_text(&family.repeat(2), 7, "…", LengthCounting::Chars)
+        );
+        assert_eq!(
+            Some("cafe\u{301}…"),
+            truncate_text("cafe\u{301}st", 6, "…", LengthCounting::Chars).as_deref()
+        );
+        assert_eq!(
+            Some("caf…"),
+            truncate_text("cafe\u{301}st", 5, "…", LengthCounting::Chars).as_deref()
+        );
+    }
+
+    #[test]
+    fn text_markdown() {
+        assert_eq!(
+            Some("some…"),
+            truncate_text("some **bold text**", 10, "…", LengthCounting::Chars).as_deref()
+        );
+        assert_eq!(
+            Some("see…"),
+            truncate_text(
+                "see [the docs](https://twilight.rs) now",
+                20,
+                "…",
+                LengthCounting::Chars
+            )
+            .as_deref()
+        );
+        assert_eq!(
+            Some("hi…"),
+            truncate_text(
+                "hi <@123456789012345678> there",
+                10,
+                "…",
+                LengthCounting::Chars
+            )
+            .as_deref()
+        );
+        assert_eq!(
+            Some("* a list item…"),
+            truncate_text("* a list item with more", 15, "…", LengthCounting::Chars).as_deref()
+        );
+    }
+
+    #[test]
+    fn text_blank() {
+        assert_eq!(
+            Some("h"),
+            truncate_text(" hello", 1, "…", LengthCounting::Chars).as_deref()
+        );
+        assert_eq!(
+            Some("a"),
+            truncate_text("\u{200b}\n abc", 1, "…", LengthCounting::Chars).as_deref()
+        );
+        assert_eq!(
+            Some("*"),
+            truncate_text("**bold**", 1, "…", LengthCounting::Chars).as_deref()
+        );
+        assert_eq!(
+            Some("🦄"),
+            truncate_text("🦄🦄", 1, "…", LengthCounting::Utf16).as_deref()
+        );
+    }
+
+    #[test]
+    fn text_untrusted() {
+        for text in ["<", "[", "[a](", "**a", "`"] {
+            let text = text.repeat(50_000);
+
+            assert!(truncate_text(&text, 4096, "…", LengthCounting::Chars).is_some());
+        }
+
+        assert_eq!(
+            Some("a…"),
+            truncate_text(
+                &format!("a [b]({})", "c".repeat(50_000)),
+                4096,
+                "…",
+                LengthCounting::Chars
+            )
+            .as_deref()
+        );
+    }
+
+    #[test]
+    fn build_parts() {
+        let (embed, truncations) = EmbedBuilder::new()
+            .description("a".repeat(EmbedBuilder::DESCRIPTION_LENGTH_LIMIT + 10))
+            .field(EmbedFieldBuilder::new("name", "value"))
+            .build_truncated("…")
+            .unwrap();
+
+        assert_eq!(
+            EmbedBuilder::DESCRIPTION_LENGTH_LIMIT,
+            embed.description.unwrap().chars().count()
+        );
+        assert_eq!(1, truncations.len());
+        assert_eq!(EmbedPart::Description, truncations[0].part());
+        assert_eq!(
+            EmbedBuilder::DESCRIPTION_LENGTH_LIMIT + 10,
+            truncations[0].original_length()
+        );
+        assert_eq!(10, truncations[0].removed());
+    }
+
+    #[test]
+    fn build_total() {
+        let (embed, truncations) = EmbedBuilder::new()
+            .description("a".repeat(EmbedBuilder::DESCRIPTION_LENGTH_LIMIT))
+            .field(EmbedFieldBuilder::new("b", "b".repeat(1024)))
+            .field(EmbedFieldBuilder::new("c", "c".repeat(1024)))
+            .build_truncated("…")
+            .unwrap();
+
+        let total = embed.description.as_ref().unwrap().chars().count()
+            + embed
+                .fields
+                .iter()
+                .map(|field| field.name.chars().count() + field.value.chars().count())
+                .sum::<usize>();
+
+        assert_eq!(EmbedBuilder::EMBED_LENGTH_LIMIT, total);
+        assert_eq!(1, truncations.len());
+        assert_eq!(EmbedPart::Description, truncations[0].part());
+    }
+
+    #[test]
+    fn build_total_minimum() {
+        let mut builder = EmbedBuilder::new()
+            .description(" hello")
+            .footer(EmbedFooterBuilder::new("f".repeat(2048)))
+            .title("t".repeat(256));
+
+        for _ in 0..EmbedBuilder::EMBED_FIELD_LIMIT {
+            builder = builder.field(EmbedFieldBuilder::new("n".repeat(256), "v".repeat(1024)));
+        }
+
+        let (embed, truncations) = builder.build_truncated("…").unwrap();
+
+        assert_eq!(Some("h"), embed.description.as_deref());
+        assert_eq!(EmbedPart::Description, truncations[0].part());
+        assert_eq!(5, truncations[0].removed());
+        assert!(embed.fields.iter().all(|field| !field.value.is_empty()));
+    }
+
+    #[test]
+    fn part_not_shortened() {
+        let mut embed = EmbedBuilder::new()
+            .author(EmbedAuthorBuilder::new("🦄".to_owned()))
+            .0;
+        let mut truncations = Vec::new();
+
+        assert_eq!(
+            0,
+            truncate_part(
+                &mut embed,
+                EmbedPart::AuthorName,
+                1,
+                "…",
+                LengthCounting::Utf16,
+                &mut truncations,
+            )
+        );
+        assert_eq!("🦄", embed.author.unwrap().name);
+        assert!(truncations.is_empty());
+    }
+
+    #[test]
+    fn build_errors() {
+        assert!(EmbedBuilder::new().title("").build_truncated("…").is_err());
+    }
+}
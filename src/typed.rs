@@ -0,0 +1,277 @@
+//! Create embeds with a builder that requires content before building.
+//!
+//! [`EmbedBuilder::build`] accepts an embed without any content, which
+//! Discord rejects. [`TypedEmbedBuilder`] tracks whether content has been set
+//! in its type, so that building an empty embed doesn't compile.
+
+use crate::{
+    color::Color, image_source::ImageSource, length::LengthCounting, truncate::Truncation,
+    EmbedBuilder, EmbedError,
+};
+use std::marker::PhantomData;
+use twilight_model::{
+    channel::embed::{Embed, EmbedAuthor, EmbedField, EmbedFooter},
+    util::Timestamp,
+};
+
+/// State of a [`TypedEmbedBuilder`] without any content.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Empty {}
+
+/// State of a [`TypedEmbedBuilder`] with content that can be built.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HasContent {}
+
+/// State of a [`TypedEmbedBuilder`].
+///
+/// This is sealed and implemented by [`Empty`] and [`HasContent`].
+pub trait EmbedState: sealed::Sealed {}
+
+impl EmbedState for Empty {}
+
+impl EmbedState for HasContent {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Empty {}
+
+    impl Sealed for super::HasContent {}
+}
+
+/// Create an embed with a builder that can only be built once it has content.
+///
+/// Setting the author, description, footer, image or title, or adding a
+/// field, moves the builder into the [`HasContent`] state, which is the only
+/// state that can be built. The text set is still validated when building.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_embed_builder::{Color, TypedEmbedBuilder};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let embed = TypedEmbedBuilder::new()
+///     .color(Color::new(0xfd_69_b3)?)
+///     .title("twilight")
+///     .build()?;
+/// # Ok(()) }
+/// ```
+///
+/// Building a builder without content doesn't compile:
+///
+/// ```compile_fail
+/// use twilight_embed_builder::{Color, TypedEmbedBuilder};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let embed = TypedEmbedBuilder::new()
+///     .color(Color::new(0xfd_69_b3)?)
+///     .build()?;
+/// # Ok(()) }
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into an embed"]
+pub struct TypedEmbedBuilder<S: EmbedState = Empty> {
+    inner: EmbedBuilder,
+    state: PhantomData<S>,
+}
+
+impl TypedEmbedBuilder<Empty> {
+    /// Create a new builder without any content.
+    pub const fn new() -> Self {
+        Self {
+            inner: EmbedBuilder::new(),
+            state: PhantomData,
+        }
+    }
+}
+
+impl TypedEmbedBuilder<HasContent> {
+    /// Build this into an embed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`EmbedBuilder::build`].
+    #[must_use = "should be used as part of something like a message"]
+    pub fn build(self) -> Result<Embed, EmbedError> {
+        self.inner.build()
+    }
+
+    /// Build this into an embed, truncating text that is too long instead of
+    /// failing.
+    ///
+    /// Refer to [`EmbedBuilder::build_truncated`] for how text is truncated.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`EmbedBuilder::build_truncated`].
+    pub fn build_truncated(self, ellipsis: &str) -> Result<(Embed, Vec<Truncation>), EmbedError> {
+        self.inner.build_truncated(ellipsis)
+    }
+}
+
+impl<S: EmbedState> TypedEmbedBuilder<S> {
+    /// Set the author.
+    ///
+    /// Refer to [`EmbedBuilder::author`] for more information.
+    pub fn author(self, author: impl Into<EmbedAuthor>) -> TypedEmbedBuilder<HasContent> {
+        self.content(|inner| inner.author(author))
+    }
+
+    /// Set the color.
+    ///
+    /// Refer to [`EmbedBuilder::color`] for more information.
+    pub fn color(self, color: Color) -> Self {
+        self.map(|inner| inner.color(color))
+    }
+
+    /// Set the description.
+    ///
+    /// Refer to [`EmbedBuilder::description`] for more information.
+    pub fn description(self, description: impl Into<String>) -> TypedEmbedBuilder<HasContent> {
+        self.content(|inner| inner.description(description))
+    }
+
+    /// Add a field to the embed.
+    ///
+    /// Refer to [`EmbedBuilder::field`] for more information.
+    pub fn field(self, field: impl Into<EmbedField>) -> TypedEmbedBuilder<HasContent> {
+        self.content(|inner| inner.field(field))
+    }
+
+    /// Add multiple fields to the embed, after any existing fields.
+    ///
+    /// Since there may not be any fields, this doesn't change the state of
+    /// the builder.
+    ///
+    /// Refer to [`EmbedBuilder::fields`] for more information.
+    pub fn fields(self, fields: impl IntoIterator<Item = impl Into<EmbedField>>) -> Self {
+        self.map(|inner| inner.fields(fields))
+    }
+
+    /// Set the footer.
+    ///
+    /// Refer to [`EmbedBuilder::footer`] for more information.
+    pub fn footer(self, footer: impl Into<EmbedFooter>) -> TypedEmbedBuilder<HasContent> {
+        self.content(|inner| inner.footer(footer))
+    }
+
+    /// Set the image.
+    ///
+    /// Refer to [`EmbedBuilder::image`] for more information.
+    pub fn image(self, image_source: ImageSource) -> TypedEmbedBuilder<HasContent> {
+        self.content(|inner| inner.image(image_source))
+    }
+
+    /// Consume the builder, returning the untyped builder.
+    pub fn into_inner(self) -> EmbedBuilder {
+        self.inner
+    }
+
+    /// Set how the length of text is counted against the limits.
+    ///
+    /// Refer to [`EmbedBuilder::length_counting`] for more information.
+    pub fn length_counting(self, counting: LengthCounting) -> Self {
+        self.map(|inner| inner.length_counting(counting))
+    }
+
+    /// Set the thumbnail.
+    ///
+    /// Refer to [`EmbedBuilder::thumbnail`] for more information.
+    pub fn thumbnail(self, image_source: ImageSource) -> Self {
+        self.map(|inner| inner.thumbnail(image_source))
+    }
+
+    /// Set the ISO 8601 timestamp.
+    pub fn timestamp(self, timestamp: Timestamp) -> Self {
+        self.map(|inner| inner.timestamp(timestamp))
+    }
+
+    /// Set the title.
+    ///
+    /// Refer to [`EmbedBuilder::title`] for more information.
+    pub fn title(self, title: impl Into<String>) -> TypedEmbedBuilder<HasContent> {
+        self.content(|inner| inner.title(title))
+    }
+
+    /// Set the URL.
+    ///
+    /// Refer to [`EmbedBuilder::url`] for more information.
+    pub fn url(self, url: impl Into<String>) -> Self {
+        self.map(|inner| inner.url(url))
+    }
+
+    /// Move an updated builder into the [`HasContent`] state.
+    fn content(
+        self,
+        f: impl FnOnce(EmbedBuilder) -> EmbedBuilder,
+    ) -> TypedEmbedBuilder<HasContent> {
+        TypedEmbedBuilder {
+            inner: f(self.inner),
+            state: PhantomData,
+        }
+    }
+
+    /// Update the builder without changing its state.
+    fn map(self, f: impl FnOnce(EmbedBuilder) -> EmbedBuilder) -> Self {
+        Self {
+            inner: f(self.inner),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<S: EmbedState> AsRef<Embed> for TypedEmbedBuilder<S> {
+    fn as_ref(&self) -> &Embed {
+        self.inner.as_ref()
+    }
+}
+
+impl Default for TypedEmbedBuilder<Empty> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: EmbedState> From<TypedEmbedBuilder<S>> for EmbedBuilder {
+    fn from(builder: TypedEmbedBuilder<S>) -> Self {
+        builder.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Empty, HasContent, TypedEmbedBuilder};
+    use crate::{EmbedBuilder, EmbedFieldBuilder};
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+    use std::fmt::Debug;
+
+    assert_impl_all!(
+        TypedEmbedBuilder<Empty>: Clone,
+        Debug,
+        Default,
+        Eq,
+        Into<EmbedBuilder>,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_impl_all!(TypedEmbedBuilder<HasContent>: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_not_impl_any!(TypedEmbedBuilder<HasContent>: Default);
+
+    #[test]
+    fn content() {
+        let embed = TypedEmbedBuilder::new()
+            .fields(Vec::<EmbedFieldBuilder>::new())
+            .url("https://example.com")
+            .field(EmbedFieldBuilder::new("name", "value"))
+            .title("title")
+            .build()
+            .unwrap();
+
+        assert_eq!(Some("title"), embed.title.as_deref());
+        assert_eq!(1, embed.fields.len());
+        assert!(TypedEmbedBuilder::new().description("").build().is_err());
+    }
+}